@@ -0,0 +1,254 @@
+use std::io;
+
+use image::DynamicImage;
+
+use crate::basics::*;
+use crate::image_io::{self, HdrImage};
+use crate::tone_mapping::{ToneMapping, ToneMappingOperator};
+
+
+// Arbitrary output variables, i.e. the additional buffers which can be rendered
+// together with the image to debug the shading
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Aov {
+    Depth,
+    Normal,
+    ObjectId,
+    Albedo,
+    Diffuse,
+    Specular,
+    Reflection,
+}
+
+
+static ALL_AOVS: [Aov; 7] = [
+    Aov::Depth, Aov::Normal, Aov::ObjectId, Aov::Albedo,
+    Aov::Diffuse, Aov::Specular, Aov::Reflection,
+];
+
+
+impl Aov {
+    pub fn all() -> &'static [Aov] {
+        &ALL_AOVS
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Aov::Depth => "depth",
+            Aov::Normal => "normal",
+            Aov::ObjectId => "object_id",
+            Aov::Albedo => "albedo",
+            Aov::Diffuse => "diffuse",
+            Aov::Specular => "specular",
+            Aov::Reflection => "reflection",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Aov> {
+        Aov::all().iter().find(|aov| aov.name() == name).cloned()
+    }
+
+    // Geometric AOVs can not be averaged across a pixel, so only the first sample is used for them
+    pub fn is_filterable(&self) -> bool {
+        !matches!(self, Aov::Depth | Aov::Normal | Aov::ObjectId)
+    }
+
+    // Depth and object index are scalars (although they are kept in all three color channels)
+    pub fn num_channels(&self) -> usize {
+        match self {
+            Aov::Depth | Aov::ObjectId => 1,
+            _ => 3,
+        }
+    }
+}
+
+
+// Values of all the AOVs for a single sample or pixel, each of them is stored as a color
+#[derive(Debug, Clone)]
+pub struct AovValues {
+    pub values: [Color; 7],
+}
+
+
+impl Default for AovValues {
+    fn default() -> Self {
+        AovValues::new()
+    }
+}
+
+
+impl AovValues {
+    pub fn new() -> AovValues {
+        let mut aov_values = AovValues {values: [Color::zero(); 7]};
+
+        // Nothing has been hit yet
+        aov_values.set(Aov::Depth, f32::INFINITY);
+        aov_values.set(Aov::ObjectId, -1.0);
+
+        aov_values
+    }
+
+    pub fn get(&self, aov: Aov) -> &Color {
+        &self.values[aov as usize]
+    }
+
+    pub fn set(&mut self, aov: Aov, value: f32) {
+        self.values[aov as usize] = Color::new(value, value, value);
+    }
+
+    pub fn set_color(&mut self, aov: Aov, color: &Color) {
+        self.values[aov as usize] = *color;
+    }
+
+    pub fn add_color(&mut self, aov: Aov, color: &Color) {
+        self.values[aov as usize] = &self.values[aov as usize] + color;
+    }
+
+    pub fn add_sample(&mut self, sample: &AovValues, weight: f32, is_first_sample: bool) {
+        for aov in Aov::all() {
+            if aov.is_filterable() {
+                self.add_color(*aov, &(sample.get(*aov) * weight));
+            } else if is_first_sample {
+                self.set_color(*aov, sample.get(*aov));
+            }
+        }
+    }
+}
+
+
+pub fn create_aov_images(width: u32, height: u32, aov_values: &[AovValues]) -> Vec<(Aov, HdrImage)> {
+    Aov::all()
+        .iter()
+        .map(|aov| (*aov, HdrImage::new(width, height, aov_values.iter().map(|v| *v.get(*aov)).collect())))
+        .collect()
+}
+
+
+// EXR channels of an AOV layer, e.g. `normal.X`, `normal.Y`, `normal.Z`
+pub fn compute_exr_channels(aov: Aov, img: &HdrImage) -> Vec<(String, Vec<f32>)> {
+    let channel_names: &[&str] = match aov {
+        Aov::Depth => &["Z"],
+        Aov::ObjectId => &["id"],
+        Aov::Normal => &["X", "Y", "Z"],
+        _ => &["R", "G", "B"],
+    };
+
+    img.to_channels()
+        .into_iter()
+        .take(aov.num_channels())
+        .zip(channel_names.iter())
+        .map(|((_, values), name)| (format!("{}.{}", aov.name(), name), values))
+        .collect()
+}
+
+
+// Stores the image as the default RGB layer and every AOV as a separate layer
+pub fn write_layered_exr(path: &str, img: &HdrImage, aov_images: &[(Aov, HdrImage)]) -> io::Result<()> {
+    let mut channels = img.to_channels();
+
+    for (aov, aov_img) in aov_images {
+        channels.extend(compute_exr_channels(*aov, aov_img));
+    }
+
+    image_io::write_exr(path, img.width, img.height, &channels)
+}
+
+
+// Maps an AOV into [0, 1], so it can be saved into an 8-bit image
+pub fn visualize_aov(aov: Aov, img: &HdrImage) -> HdrImage {
+    let pixels = match aov {
+        Aov::Depth => {
+            // Inverse depth, since planes make the maximum depth enormous
+            let min_depth = img.pixels.iter().map(|c| c.r).filter(|d| *d > 0.0).fold(f32::INFINITY, f32::min);
+
+            img.pixels.iter()
+                .map(|c| if c.r.is_finite() && c.r > 0.0 { &Color::new(1.0, 1.0, 1.0) * (min_depth / c.r) } else { Color::zero() })
+                .collect()
+        },
+        Aov::Normal => img.pixels.iter().map(|c| Color::new(0.5 * c.r + 0.5, 0.5 * c.g + 0.5, 0.5 * c.b + 0.5)).collect(),
+        Aov::ObjectId => img.pixels.iter().map(|c| compute_id_color(c.r)).collect(),
+        _ => img.pixels.clone(),
+    };
+
+    HdrImage::new(img.width, img.height, pixels)
+}
+
+
+// Saves an AOV into an 8-bit image. Depth, normals and object indices are data, so they are quantized
+// linearly, while the shading AOVs are radiance and get the sRGB encoding like the image itself
+pub fn create_aov_ldr_image(aov: Aov, img: &HdrImage) -> DynamicImage {
+    let visualization = visualize_aov(aov, img);
+
+    match aov {
+        Aov::Depth | Aov::Normal | Aov::ObjectId => visualization.to_ldr_linear(),
+        _ => visualization.to_ldr(&ToneMapping::new(ToneMappingOperator::Clamp)),
+    }
+}
+
+
+fn compute_id_color(id: f32) -> Color {
+    if id < 0.0 {
+        return Color::zero();
+    }
+
+    // Golden ratio hue stepping gives distinct colors for neighbouring indices
+    let hue = (id * 0.618034).fract() * 6.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+
+    match hue as u32 {
+        0 => Color::new(1.0, x, 0.0),
+        1 => Color::new(x, 1.0, 0.0),
+        2 => Color::new(0.0, 1.0, x),
+        3 => Color::new(0.0, x, 1.0),
+        4 => Color::new(x, 0.0, 1.0),
+        _ => Color::new(1.0, 0.0, x),
+    }
+}
+
+
+#[cfg(test)]
+mod aov_tests {
+    use super::*;
+
+    #[test]
+    fn test_aov_names() {
+        for aov in Aov::all() {
+            assert_eq!(Aov::from_name(aov.name()), Some(*aov));
+        }
+
+        assert_eq!(Aov::from_name("beauty"), None);
+    }
+
+    #[test]
+    fn test_add_sample() {
+        let mut pixel = AovValues::new();
+        let mut sample = AovValues::new();
+        sample.set(Aov::ObjectId, 2.0);
+        sample.set(Aov::Diffuse, 1.0);
+
+        pixel.add_sample(&sample, 0.5, true);
+        sample.set(Aov::ObjectId, 3.0);
+        pixel.add_sample(&sample, 0.5, false);
+
+        assert_eq!(pixel.get(Aov::ObjectId).r, 2.0);
+        assert_eq!(pixel.get(Aov::Diffuse).g, 1.0);
+    }
+
+    #[test]
+    fn test_exr_channels() {
+        let img = HdrImage::new(1, 1, vec![Color::new(0.0, 1.0, 0.0)]);
+        let channels = compute_exr_channels(Aov::Normal, &img);
+        let names = channels.iter().map(|c| c.0.as_str()).collect::<Vec<&str>>();
+
+        assert_eq!(names, vec!["normal.X", "normal.Y", "normal.Z"]);
+        assert_eq!(compute_exr_channels(Aov::Depth, &img).len(), 1);
+    }
+
+    #[test]
+    fn test_normal_ldr_is_linear() {
+        let img = HdrImage::new(1, 1, vec![Color::new(0.0, 1.0, 0.0)]);
+        let ldr = create_aov_ldr_image(Aov::Normal, &img).to_rgb8();
+
+        assert_eq!(ldr.get_pixel(0, 0).0, [128, 255, 128]);
+    }
+}
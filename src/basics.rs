@@ -1,10 +1,14 @@
 use std::cmp::PartialEq;
 use std::ops;
-use nannou::image::{Rgb};
+use image::{Rgb};
 use derive_more;
 
+use crate::tone_mapping::{linear_to_srgb, srgb_to_linear};
 
-#[derive(Debug, Copy, Clone)]
+
+// Linear RGB radiance. It is not clamped, so it is converted to the displayable
+// range only at the very end (see `ToneMapping` and `From<Color> for Rgb<u8>`)
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Color {
     pub r: f32,
     pub g: f32,
@@ -14,13 +18,18 @@ pub struct Color {
 
 impl Color {
     pub fn new(r: f32, g: f32, b: f32) -> Color {
-        (&Color {r: r, g: g, b: b}).clamp()
+        Color {r: r, g: g, b: b}
     }
 
     pub fn zero() -> Color {
         Color::new(0.0, 0.0, 0.0)
     }
 
+    // Decodes a color picked in the sRGB space, e.g. the ones written in scene files
+    pub fn from_srgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b))
+    }
+
     pub fn clamp(&self) -> Color {
         Color {
             r: self.r.max(0.0).min(1.0),
@@ -29,12 +38,8 @@ impl Color {
         }
     }
 
-    pub fn add_no_clamp(&self, other: &Color) -> Color {
-        Color {
-            r: self.r + other.r,
-            g: self.g + other.g,
-            b: self.b + other.b,
-        }
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
     }
 }
 
@@ -43,11 +48,24 @@ impl ops::Mul<f32> for &Color {
     type Output = Color;
 
     fn mul(self, strength: f32) -> Color {
-        (Color {
+        Color {
             r: self.r * strength,
             g: self.g * strength,
             b: self.b * strength,
-        }).clamp()
+        }
+    }
+}
+
+
+impl ops::Mul<&Color> for &Color {
+    type Output = Color;
+
+    fn mul(self, other: &Color) -> Color {
+        Color {
+            r: self.r * other.r,
+            g: self.g * other.g,
+            b: self.b * other.b,
+        }
     }
 }
 
@@ -56,17 +74,24 @@ impl ops::Add<&Color> for &Color {
     type Output = Color;
 
     fn add(self, other: &Color) -> Color {
-        self.add_no_clamp(other).clamp()
+        Color {
+            r: self.r + other.r,
+            g: self.g + other.g,
+            b: self.b + other.b,
+        }
     }
 }
 
 
 impl From<Color> for Rgb<u8> {
     fn from(color: Color) -> Self {
+        // Expects an already tone mapped color and encodes it with the sRGB transfer function
+        let color = color.clamp();
+
         Rgb([
-            (color.r * (u8::MAX - 1) as f32) as u8,
-            (color.g * (u8::MAX - 1) as f32) as u8,
-            (color.b * (u8::MAX - 1) as f32) as u8,
+            (linear_to_srgb(color.r) * u8::MAX as f32).round() as u8,
+            (linear_to_srgb(color.g) * u8::MAX as f32).round() as u8,
+            (linear_to_srgb(color.b) * u8::MAX as f32).round() as u8,
         ])
     }
 }
@@ -289,7 +314,8 @@ pub struct Light {
 #[derive(Debug, Clone)]
 pub struct Ray {
     pub origin: Point,
-    pub direction: Vec3
+    pub direction: Vec3,
+    pub time: f32, // Within the shutter interval, which is mapped onto [0, 1]
 }
 
 impl Ray {
@@ -311,6 +337,9 @@ pub enum MeshNormalType {Provided, Precomputed, Face}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BVType {BBox, Sphere, None}
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Integrator {Whitted, PathTracing}
+
 
 #[derive(Debug, Clone, Copy)]
 pub struct RayOptions {
@@ -0,0 +1,581 @@
+// The interactive mesh rasterizer, takes an .obj file and optionally a texture image, or a scene file
+use std::time::{Instant, Duration};
+use std::f32::consts::{PI};
+use std::env;
+use std::process;
+use std::sync::Arc;
+use std::path::Path;
+
+use nannou::prelude::*;
+use image::{DynamicImage};
+use tobj::Mesh;
+
+use rtrs::matrix::*;
+use rtrs::basics::*;
+use rtrs::tone_mapping::{ToneMapping, ToneMappingOperator};
+use rtrs::image_io::{self, HdrImage};
+use rtrs::texture::{Texture, WrapMode};
+use rtrs::surface::mesh::{load_obj, visual_data_from_material};
+use rtrs::error::RtrsError;
+use rtrs::pipeline::{self, FrameBuffer, Projection, VertexShader, FragmentShader};
+use rtrs::shaders::{Lighting, ShaderUniforms, FlatShader, GouraudShader, PhongShader, TextureShader, StripeShader};
+use rtrs::camera::{CameraOptions, ViewingPlane};
+use rtrs::scene_file::{SceneDescription, Shape, format_camera_statement};
+use rtrs::surface::surface::VisualData;
+
+// const WIDTH: usize = 640;
+// const HEIGHT: usize = 480;
+const WIDTH: usize = 1280;
+const HEIGHT: usize = 960;
+
+#[derive(Debug, Clone)]
+struct State {
+    objects: Vec<RasterObject>,
+    camera: Camera,
+    curr_mouse_x: f32,
+    curr_mouse_y: f32,
+    scene_center: Point, // The arcball rotates the scene around it
+    scene_to_world: AffineMat3,
+    arcball_enabled: bool,
+    mouse_look_enabled: bool,
+    mouse_sensitivity: f32,
+    move_speed: f32,
+    lighting: Lighting, // The light position is in the world space
+    background_color: Color,
+    shading: Shading,
+    is_antialiasing: bool,
+    specular_lighting_enabled: bool,
+    tex_enabled: bool,
+    scroll_speed: f32,
+    backface_culling_enabled: bool,
+    tone_mapping: ToneMapping,
+}
+
+// A mesh with its own transformation and material, every model of an .obj file is a separate object
+#[derive(Debug, Clone)]
+struct RasterObject {
+    mesh: Arc<Mesh>,
+    object_to_world: AffineMat3,
+    color: Color, // Kd of the model material
+    texture: Option<Arc<Texture>>, // The stripes are drawn if it is not given
+}
+
+// The viewpoint is the same as the one of the ray tracer, `format_camera_statement` gives it for scene files
+#[derive(Debug, Clone)]
+struct Camera {
+    pub opts: CameraOptions,
+    pub fov: f32,
+    pub near_clipping_plane: f32,
+    pub far_clipping_plane: f32,
+}
+
+// The built-in lighting shaders, `L` switches between them
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shading {
+    Flat,
+    Gouraud,
+    Phong,
+}
+
+
+impl Shading {
+    fn next(&self) -> Shading {
+        match self {
+            Shading::Flat => Shading::Gouraud,
+            Shading::Gouraud => Shading::Phong,
+            Shading::Phong => Shading::Flat,
+        }
+    }
+}
+
+
+impl Camera {
+    fn compute_view_matrix(&self) -> AffineMat3 {
+        self.opts.compute_view_matrix()
+    }
+
+    fn compute_arcball_vector_for_xy(x: f32, y: f32) -> Vec3 {
+        // Computing x, y, in [-1, 1] coords
+        // Nannou gives (x, y) coords in [-w/2, w/2] and [-h/2, h/2] formats
+        let mut result = Vec3::new(2.0 * x / WIDTH as f32, 2.0 * y / HEIGHT as f32, 0.0);
+
+        // Now we pretend that there is a ball which touches (0, 0) point
+        // and which center is located in the object center
+        // We want to project the point back to this sphere
+        let curr_norm = (result.x.powi(2) + result.y.powi(2)).sqrt();
+        if curr_norm < 1.0 {
+            result.z = (1.0 - curr_norm * curr_norm).sqrt();
+        } else {
+            result = result.normalize();
+        }
+
+        result
+    }
+
+    // The viewing plane is the same as the one of the ray tracer, so both renderers have the same field of view
+    pub fn compute_projection(&self, frame_width: usize, frame_height: usize) -> Projection {
+        Projection {
+            viewing_plane: ViewingPlane::from_fov(self.fov, frame_width as u32, frame_height as u32),
+            near_clipping_plane: self.near_clipping_plane,
+            far_clipping_plane: self.far_clipping_plane,
+        }
+    }
+}
+
+
+fn main() {
+    nannou::app(init_app).event(update_on_event).run();
+}
+
+
+fn update_on_event(app: &App, state: &mut State, event: Event) {
+    match event {
+        Event::WindowEvent {id: _, simple: window_event } => {
+            if window_event.is_none() {
+                return;
+            }
+
+            match window_event.unwrap() {
+                MousePressed(MouseButton::Right) => {
+                    state.mouse_look_enabled = true;
+                    state.curr_mouse_x = app.mouse.x;
+                    state.curr_mouse_y = app.mouse.y;
+                },
+                MousePressed(button) => {
+                    if button != MouseButton::Left {
+                        return;
+                    }
+
+                    state.arcball_enabled = true;
+                    state.curr_mouse_x = app.mouse.x;
+                    state.curr_mouse_y = app.mouse.y;
+                },
+                MouseReleased(MouseButton::Right) => state.mouse_look_enabled = false,
+                MouseReleased(button) => {
+                    if button != MouseButton::Left {
+                        return;
+                    }
+
+                    state.arcball_enabled = false;
+                },
+                KeyPressed(key) => {
+                    if key == Key::L {
+                        state.shading = state.shading.next();
+                        println!("Set shading to {:?}", state.shading);
+                    }
+
+                    if key == Key::I {
+                        state.is_antialiasing = !state.is_antialiasing;
+                    }
+
+                    if key == Key::Q {
+                        state.specular_lighting_enabled = !state.specular_lighting_enabled;
+                    }
+
+                    if key == Key::T {
+                        state.tex_enabled = !state.tex_enabled;
+                    }
+
+                    if key == Key::B {
+                        state.backface_culling_enabled = !state.backface_culling_enabled;
+                    }
+
+                    if key == Key::M {
+                        state.tone_mapping.operator = state.tone_mapping.operator.next();
+                        println!("Set tone mapping operator to {:?}", state.tone_mapping.operator);
+                    }
+
+                    if key == Key::Minus || key == Key::Equals {
+                        state.tone_mapping.exposure += if key == Key::Minus {-0.5} else {0.5};
+                        println!("Set exposure to {}", state.tone_mapping.exposure);
+                    }
+
+                    if key == Key::P {
+                        render_state(state).save("image.png").unwrap();
+                        println!("Saved the image!");
+                    }
+
+                    if key == Key::C {
+                        println!("{}", format_camera_statement(&state.camera.opts, state.camera.fov));
+                    }
+
+                    if key == Key::X {
+                        image_io::save_hdr("image.exr", &render_hdr(state)).unwrap();
+                        println!("Saved the image to image.exr");
+                    }
+                },
+                MouseWheel(scroll_delta, _) => {
+                    match scroll_delta {
+                        MouseScrollDelta::PixelDelta(position) => {
+                            state.camera.fov += (position.y as f32) * state.scroll_speed;
+                            state.camera.fov = state.camera.fov.min(PI * 165.0 / 180.0).max(PI * 15.0 / 180.0);
+                        }
+                        _ => {}
+                    }
+                },
+                _ => {}
+            }
+        },
+        _ => {},
+    }
+
+    process_pressed_keys(app, state);
+
+    if state.curr_mouse_x == app.mouse.x && state.curr_mouse_y == app.mouse.y {
+        return;
+    }
+
+    if state.mouse_look_enabled {
+        let offset_x = (app.mouse.x - state.curr_mouse_x) * state.mouse_sensitivity;
+        let offset_y = (state.curr_mouse_y - app.mouse.y) * state.mouse_sensitivity;
+
+        state.camera.opts.turn_by(offset_x, offset_y);
+        state.curr_mouse_x = app.mouse.x;
+        state.curr_mouse_y = app.mouse.y;
+        return;
+    }
+
+    if !state.arcball_enabled {
+        return;
+    }
+
+    let prev_arcball_vec = Camera::compute_arcball_vector_for_xy(state.curr_mouse_x, state.curr_mouse_y);
+    let curr_arcball_vec = Camera::compute_arcball_vector_for_xy(app.mouse.x, app.mouse.y);
+    let angle = 2.0 * prev_arcball_vec.dot_product(&curr_arcball_vec).min(1.0).acos();
+    let world_to_camera = state.camera.compute_view_matrix();
+    let camera_to_world = &world_to_camera.compute_inverse();
+    let axis_camera = &prev_arcball_vec.cross_product(&curr_arcball_vec);
+    let axis_world = (camera_to_world * axis_camera).normalize();
+    let center: Vec3 = (&state.scene_center).into();
+    let rotation = &(&AffineMat3::translation(center.clone()) * &AffineMat3::rotation(angle, &axis_world)) * &AffineMat3::translation(-&center);
+
+    state.scene_to_world = &rotation * &state.scene_to_world;
+    state.curr_mouse_x = app.mouse.x;
+    state.curr_mouse_y = app.mouse.y;
+}
+
+
+// WASD moves the camera in the same way as in the ray tracer
+fn process_pressed_keys(app: &App, state: &mut State) {
+    if app.keys.down.contains(&Key::W) {
+        state.camera.opts.move_by(state.move_speed, 0.0);
+    }
+
+    if app.keys.down.contains(&Key::S) {
+        state.camera.opts.move_by(-state.move_speed, 0.0);
+    }
+
+    if app.keys.down.contains(&Key::D) {
+        state.camera.opts.move_by(0.0, state.move_speed);
+    }
+
+    if app.keys.down.contains(&Key::A) {
+        state.camera.opts.move_by(0.0, -state.move_speed);
+    }
+}
+
+
+// Every model of the file is a separate object, with its .mtl material if `use_materials` is set
+fn load_objects(
+    obj_file: &str, object_to_world: &AffineMat3, default_vis: &VisualData,
+    use_materials: bool) -> Result<Vec<RasterObject>, RtrsError> {
+
+    let obj_path = Path::new(obj_file);
+    let base_dir = obj_path.parent().unwrap_or(Path::new(""));
+    let (models, materials) = load_obj(obj_path)?;
+    let objects = models.into_iter()
+        .filter(|model| model.mesh.indices.len() > 0)
+        .map(|model| {
+            let material = model.mesh.material_id.and_then(|idx| materials.get(idx)).filter(|_| use_materials);
+            let vis = match material {
+                Some(material) => visual_data_from_material(material, base_dir, default_vis),
+                None => default_vis.clone(),
+            };
+
+            RasterObject {
+                mesh: Arc::new(model.mesh),
+                object_to_world: object_to_world.clone(),
+                color: vis.color,
+                texture: vis.diffuse_texture,
+            }
+        })
+        .collect::<Vec<RasterObject>>();
+
+    if objects.is_empty() {
+        return Err(RtrsError::EmptyMesh(obj_path.to_path_buf()));
+    }
+
+    Ok(objects)
+}
+
+
+// Only the meshes of the scene are rasterized, the other shapes are skipped
+fn load_scene_objects(scene_file: &str, description: &SceneDescription) -> Result<Vec<RasterObject>, RtrsError> {
+    let base_dir = Path::new(scene_file).parent().unwrap_or(Path::new(""));
+    let mut objects = vec![];
+
+    for object in description.objects.iter() {
+        let (path, use_materials) = match &object.shape {
+            Shape::Mesh {path, mesh_idx: _, use_materials} => (path, *use_materials),
+            shape => {
+                println!("Skipping {:?}, only meshes are rasterized", shape);
+                continue;
+            },
+        };
+        let mut vis = object.vis.clone();
+
+        if let Some(texture_idx) = object.texture_idx {
+            vis.diffuse_texture = description.textures.get(texture_idx).cloned();
+        }
+
+        let obj_file = base_dir.join(path);
+        objects.extend(load_objects(&obj_file.to_string_lossy(), &object.transformation, &vis, use_materials)?);
+    }
+
+    if objects.is_empty() {
+        return Err(RtrsError::InvalidSceneConfig("the scene has no meshes to rasterize".to_string()));
+    }
+
+    Ok(objects)
+}
+
+
+// The camera, the first light and the background are taken from the scene
+fn load_scene_state(scene_file: &str) -> Result<State, RtrsError> {
+    let mut description = SceneDescription::parse_file(scene_file)?;
+    description.load_textures(Path::new(scene_file).parent().unwrap_or(Path::new("")))?;
+
+    let objects = load_scene_objects(scene_file, &description)?;
+    let scene_center = compute_center(&objects);
+    let camera_distance = (&Point::from(description.camera_opts.position.clone()) - &scene_center).norm();
+    let mut state = init_state(objects, scene_center, camera_distance);
+
+    state.camera.opts = description.camera_opts.clone();
+    state.camera.fov = description.fov;
+    state.background_color = description.background_color;
+    state.lighting.ambient = description.ambient_strength;
+    state.lighting.diffuse = description.diffuse_strength;
+
+    if let Some(light) = description.lights.first() {
+        state.lighting.light_position = light.location.clone();
+    }
+
+    Ok(state)
+}
+
+
+// The objects are moved so that the center of the model is at the origin
+fn load_obj_state(obj_file: &str) -> Result<State, RtrsError> {
+    let mut objects = load_objects(obj_file, &AffineMat3::identity(), &VisualData::from_color(&Color::new(1.0, 1.0, 1.0)), true)?;
+    let object_center = compute_center(&objects);
+
+    for object in objects.iter_mut() {
+        object.object_to_world = AffineMat3::translation((&-&object_center).into());
+    }
+
+    let camera_distance = match obj_file {
+        "resources/KAUST_Beacon.obj" => -800.0,
+        "resources/bs_ears.obj" => -2.0,
+        "resources/teapot.obj" => -100.0,
+        _ => -10.0,
+    };
+    let mut state = init_state(objects, Point::zero(), camera_distance);
+
+    // The stripes are drawn instead of a texture which could not be loaded
+    if let Some(texture_file) = env::args().skip(2).next() {
+        match Texture::from_file(&texture_file, WrapMode::Repeat) {
+            Ok(texture) => {
+                let texture = Arc::new(texture);
+
+                for object in state.objects.iter_mut() {
+                    object.texture = Some(texture.clone());
+                }
+            },
+            Err(err) => eprintln!("Could not load the texture: {}", err),
+        }
+    }
+
+    Ok(state)
+}
+
+
+// The mean of all the vertices in the world space
+fn compute_center(objects: &[RasterObject]) -> Point {
+    let mut sum = Vec3::zero();
+    let mut num_vertices = 0;
+
+    for object in objects.iter() {
+        for position in object.mesh.positions.chunks(3) {
+            let vertex = &object.object_to_world * &Point::new(position[0], position[1], position[2]);
+
+            sum = &sum + &Vec3::from(&vertex);
+        }
+
+        num_vertices += object.mesh.positions.len() / 3;
+    }
+
+    (&sum * (1.0 / num_vertices.max(1) as f32)).into()
+}
+
+
+fn init_app(app: &App) -> State {
+    let input_file = env::args().skip(1).next().unwrap_or_else(|| {
+        eprintln!("A .obj or a .scene file to rasterize is required");
+        process::exit(1);
+    });
+    let state = if input_file.ends_with(".scene") { load_scene_state(&input_file) } else { load_obj_state(&input_file) };
+    let mut state = state.unwrap_or_else(|err| {
+        eprintln!("Could not load {}: {}", input_file, err);
+        process::exit(1);
+    });
+
+    app
+        .new_window()
+        .title("RtRs")
+        .size(WIDTH as u32, HEIGHT as u32)
+        .view(render_and_display)
+        .build()
+        .unwrap();
+
+    (*app.main_window()).set_cursor_position_points(WIDTH as f32 / 2.0, HEIGHT as f32 / 2.0);
+    state.curr_mouse_x = app.mouse.x;
+    state.curr_mouse_y = app.mouse.y;
+
+    state
+}
+
+
+fn render_and_display(app: &App, state: &State, frame: Frame) {
+    frame.clear(BLACK);
+
+    let draw = app.draw();
+    let img = render_state(state);
+    let texture = wgpu::Texture::from_image(app, &img);
+
+    draw.texture(&texture);
+    draw.to_frame(app, &frame).unwrap();
+}
+
+
+fn render_state(state: &State) -> DynamicImage {
+    render_hdr(state).to_ldr(&state.tone_mapping)
+}
+
+
+fn render_hdr(state: &State) -> HdrImage {
+    let world_to_camera = state.camera.compute_view_matrix();
+    let frame_width: usize = if state.is_antialiasing {WIDTH * 2} else {WIDTH};
+    let frame_height: usize = if state.is_antialiasing {HEIGHT * 2} else {HEIGHT};
+    let scene_to_camera = &world_to_camera * &state.scene_to_world;
+    let projection = state.camera.compute_projection(frame_width, frame_height);
+    let mut lighting = state.lighting.clone();
+    lighting.light_position = &world_to_camera * &state.lighting.light_position;
+    if !state.specular_lighting_enabled {
+        lighting.specular = 0.0;
+    }
+    let mut frame = FrameBuffer::new(frame_width, frame_height, &state.background_color, state.camera.far_clipping_plane);
+
+    let start = Instant::now();
+
+    // The objects are drawn one after another into the same frame
+    for object in state.objects.iter() {
+        let uniforms = ShaderUniforms::new(
+            &scene_to_camera * &object.object_to_world,
+            projection.clone(),
+            lighting.clone(),
+            object.color,
+        );
+
+        match state.shading {
+            Shading::Flat => draw_textured(state, object, &mut frame, FlatShader {uniforms: uniforms}),
+            Shading::Gouraud => draw_textured(state, object, &mut frame, GouraudShader {uniforms: uniforms}),
+            Shading::Phong => draw_textured(state, object, &mut frame, PhongShader {uniforms: uniforms}),
+        }
+    }
+
+    let duration = start.elapsed();
+    println!("Rasterizer done! Took time: {} ms", duration.as_millis());
+
+    let mut pixels = Vec::with_capacity(WIDTH * HEIGHT);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let mut color = Color::zero();
+            if state.is_antialiasing {
+                // Mean filtering
+                color = &color + &frame.colors[WIDTH * 2 * (y * 2 + 0) + x * 2 + 0];
+                color = &color + &frame.colors[WIDTH * 2 * (y * 2 + 0) + x * 2 + 1];
+                color = &color + &frame.colors[WIDTH * 2 * (y * 2 + 1) + x * 2 + 0];
+                color = &color + &frame.colors[WIDTH * 2 * (y * 2 + 1) + x * 2 + 1];
+                color = &color * 0.25;
+            } else {
+                color = frame.colors[WIDTH * y + x];
+            };
+
+            pixels.push(color);
+        }
+    }
+
+    HdrImage::new(WIDTH as u32, HEIGHT as u32, pixels)
+}
+
+
+// Wraps the lighting shader into the texture or the stripes one if texturing is enabled
+fn draw_textured<S>(state: &State, object: &RasterObject, frame: &mut FrameBuffer, shader: S)
+    where S: VertexShader + FragmentShader<<S as VertexShader>::Varyings> {
+
+    let mesh = &*object.mesh;
+    let cull_back_faces = state.backface_culling_enabled;
+
+    if mesh.texcoords.is_empty() || !state.tex_enabled {
+        pipeline::draw_mesh(frame, mesh, &shader, cull_back_faces);
+        return;
+    }
+
+    match &object.texture {
+        Some(texture) => pipeline::draw_mesh(frame, mesh, &TextureShader {base: shader, texture: texture.clone()}, cull_back_faces),
+        None => pipeline::draw_mesh(frame, mesh, &StripeShader {base: shader, color: object.color}, cull_back_faces),
+    }
+}
+
+
+fn init_state(objects: Vec<RasterObject>, scene_center: Point, camera_distance: f32) -> State {
+    println!("Building model!");
+    println!("Scene center: {:?}", &scene_center);
+    println!("Number of objects: {}", objects.len());
+    println!("Number of vertices: {}", objects.iter().map(|object| object.mesh.positions.len() / 3).sum::<usize>());
+    println!("Number of normals: {}", objects.iter().map(|object| object.mesh.normals.len() / 3).sum::<usize>());
+    println!("Number of texcoords: {}", objects.iter().map(|object| object.mesh.texcoords.len() / 2).sum::<usize>());
+
+    State {
+        objects: objects,
+        scene_center: scene_center,
+        scene_to_world: AffineMat3::identity(),
+        camera: Camera {
+            // Looking from (0, 0, -camera_distance) towards -z, the eye is one unit in front of the position
+            opts: CameraOptions {
+                yaw: 0.5 * PI,
+                pitch: 0.0,
+                roll: 0.0,
+                position: Vec3::new(0.0, 0.0, 1.0 - camera_distance),
+            },
+            fov: PI * 0.5,
+            near_clipping_plane: 1.0,
+            far_clipping_plane: 1000.0,
+        },
+        curr_mouse_x: 0.0,
+        curr_mouse_y: 0.0,
+        arcball_enabled: false,
+        mouse_look_enabled: false,
+        mouse_sensitivity: 0.001,
+        move_speed: camera_distance.abs() * 0.01,
+        lighting: Lighting::new(Point::new(0.0, 100.0, 0.0)),
+        background_color: Color::from_srgb(236.0 / 255.0, 240.0 / 255.0, 241.0 / 255.0),
+        shading: Shading::Flat,
+        is_antialiasing: false,
+        specular_lighting_enabled: false,
+        tex_enabled: false,
+        scroll_speed: 0.01,
+        backface_culling_enabled: true,
+        tone_mapping: ToneMapping::new(ToneMappingOperator::Clamp),
+    }
+}
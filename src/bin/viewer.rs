@@ -0,0 +1,347 @@
+// The interactive ray tracer, a scene file can be passed as the first argument
+use std::time::{Instant};
+use std::env;
+use std::process;
+
+use nannou::prelude::*;
+
+use rtrs::ray_tracer::{State, WIDTH, HEIGHT, init_state, render_hdr, render_hdr_with_aovs};
+use rtrs::scene_file::{SceneDescription, format_camera_statement};
+use rtrs::basics::*;
+use rtrs::aov;
+
+
+fn main() {
+    nannou::app(init_nannou).event(update_on_event).run();
+}
+
+
+fn init_nannou(app: &App) -> State {
+    app
+        .new_window()
+        .title("RtRs")
+        .size(WIDTH, HEIGHT)
+        .view(view)
+        .build()
+        .unwrap();
+
+    let mut state = init_state().unwrap_or_else(|err| {
+        eprintln!("Could not build the state: {}", err);
+        process::exit(1);
+    });
+
+    if let Some(scene_file) = env::args().skip(1).next() {
+        match SceneDescription::from_file(&scene_file) {
+            Ok(scene_description) => state.set_scene_description(scene_description),
+            Err(err) => println!("Could not load the scene: {}", err),
+        }
+    }
+
+    state
+}
+
+
+fn update_on_event(app: &App, state: &mut State, event: Event) {
+    let is_animated = state.selected_scene_idx == 0 && state.scene_description.is_none();
+    let is_input_event = match &event {
+        Event::WindowEvent {simple: Some(KeyReleased(_)), ..} => true,
+        Event::WindowEvent {simple: Some(MouseWheel(_, _)), ..} => true,
+        _ => false,
+    };
+
+    if is_animated {
+        state.opts.update_transformations_on_time(app.time);
+    }
+
+    // The accumulated path tracing frames are only valid while nothing moves
+    if is_animated || is_input_event || !app.keys.down.is_empty() {
+        state.accumulator.get_mut().unwrap().reset();
+    }
+
+    process_pressed_keys(app, state);
+    process_mouse_events(app, state, event);
+    // process_mouse_move(app, state);
+}
+
+
+fn process_key_released_event(app: &App, state: &mut State, key: Key) {
+    match key {
+        Key::G => {
+            state.opts.ray_opts.mesh_normal_type = match state.opts.ray_opts.mesh_normal_type {
+                MeshNormalType::Provided => MeshNormalType::Precomputed,
+                MeshNormalType::Precomputed => MeshNormalType::Face,
+                MeshNormalType::Face => MeshNormalType::Provided,
+            };
+            println!("Set bvh_display_level to {:?}", state.opts.ray_opts.mesh_normal_type);
+        },
+        Key::F => {
+            state.opts.ray_opts.mesh_normal_type = match state.opts.ray_opts.mesh_normal_type {
+                MeshNormalType::Precomputed => MeshNormalType::Provided,
+                MeshNormalType::Face => MeshNormalType::Precomputed,
+                MeshNormalType::Provided => MeshNormalType::Face,
+            };
+            println!("Set mesh_normal_type to {:?}", state.opts.ray_opts.mesh_normal_type);
+        },
+        Key::B => {
+            state.opts.ray_opts.bv_type = match state.opts.ray_opts.bv_type {
+                BVType::BBox => BVType::Sphere,
+                BVType::Sphere => BVType::None,
+                BVType::None => BVType::BBox,
+            };
+            println!("Set bv_type to {:?}", state.opts.ray_opts.bv_type);
+        },
+        Key::V => {
+            state.opts.ray_opts.bv_type = match state.opts.ray_opts.bv_type {
+                BVType::Sphere => BVType::BBox,
+                BVType::None => BVType::Sphere,
+                BVType::BBox => BVType::None,
+            };
+            println!("Set bv_type to {:?}", state.opts.ray_opts.bv_type);
+        },
+        Key::Key1 => state.selected_scene_idx = 0,
+        Key::Key2 => state.selected_scene_idx = 1,
+        Key::Key3 => state.selected_scene_idx = 2,
+        Key::Key0 => state.opts.ray_opts.bvh_display_level = 0,
+        Key::Up => {
+            state.opts.ray_opts.bvh_display_level += 1;
+            println!("Set bvh_display_level to {}", state.opts.ray_opts.bvh_display_level);
+        },
+        Key::Down => {
+            state.opts.ray_opts.bvh_display_level -= 1;
+            println!("Set bvh_display_level to {}", state.opts.ray_opts.bvh_display_level);
+        },
+        Key::I => {
+            state.opts.use_supersampling = !state.opts.use_supersampling;
+            println!("Set use_supersampling to {}", state.opts.use_supersampling);
+        },
+        Key::O => {
+            state.opts.use_soft_shadows = !state.opts.use_soft_shadows;
+            println!("Set use_soft_shadows to {}", state.opts.use_soft_shadows);
+        },
+        Key::K => {
+            state.opts.use_motion_blur = !state.opts.use_motion_blur;
+            println!("Set use_motion_blur to {}", state.opts.use_motion_blur);
+        },
+        Key::R => {
+            state.opts.integrator = match state.opts.integrator {
+                Integrator::Whitted => Integrator::PathTracing,
+                Integrator::PathTracing => Integrator::Whitted,
+            };
+            println!("Set integrator to {:?}", state.opts.integrator);
+        },
+        Key::M => {
+            state.opts.tone_mapping.operator = state.opts.tone_mapping.operator.next();
+            println!("Set tone mapping operator to {:?}", state.opts.tone_mapping.operator);
+        },
+        Key::Minus => {
+            state.opts.tone_mapping.exposure -= 0.5;
+            println!("Set exposure to {}", state.opts.tone_mapping.exposure);
+        },
+        Key::Equals => {
+            state.opts.tone_mapping.exposure += 0.5;
+            println!("Set exposure to {}", state.opts.tone_mapping.exposure);
+        },
+        Key::X => {
+            match render_hdr_with_aovs(state) {
+                Ok((hdr_img, aov_images)) => {
+                    aov::write_layered_exr("image.exr", &hdr_img, &aov_images).unwrap();
+                    println!("Saved the image with all the AOVs to image.exr");
+                },
+                Err(err) => println!("Could not render the scene: {}", err),
+            }
+        },
+        Key::C => println!("{}", format_camera_statement(&state.opts.camera_opts, state.opts.fov)),
+        Key::Z => match state.frame_scene() {
+            Ok(()) => println!("Set camera position to {:?}", state.opts.camera_opts.position),
+            Err(err) => println!("Could not frame the scene: {}", err),
+        },
+        Key::P => {
+            state.opts.reflection_glossiness = if state.opts.reflection_glossiness == 0.0 {0.2} else {0.0};
+            println!("Set reflection_glossiness to {}", state.opts.reflection_glossiness);
+        },
+        Key::Q => {
+            // The current state is kept if the meshes can not be reloaded
+            match init_state() {
+                Ok(mut new_state) => {
+                    if let Some(scene_description) = state.scene_description.take() {
+                        new_state.set_scene_description(scene_description);
+                    }
+                    *state = new_state;
+                },
+                Err(err) => println!("Could not reset the state: {}", err),
+            }
+        },
+        _ => {},
+    }
+}
+
+
+fn process_pressed_keys(app: &App, state: &mut State) {
+    if app.keys.down.contains(&Key::W) {
+        state.opts.camera_opts.move_by(state.move_speed, 0.0);
+    }
+
+    if app.keys.down.contains(&Key::S) {
+        state.opts.camera_opts.move_by(-state.move_speed, 0.0);
+    }
+
+    if app.keys.down.contains(&Key::D) {
+        state.opts.camera_opts.move_by(0.0, state.move_speed);
+    }
+
+    if app.keys.down.contains(&Key::A) {
+        state.opts.camera_opts.move_by(0.0, -state.move_speed);
+    }
+
+    // if app.keys.down.contains(&Key::L) {
+    //     state.opts.selected_object_idx = Some(4); // Selecting the light
+    // }
+
+    // if let Some(idx) = state.opts.selected_object_idx {
+    //     let mut transformation = None;
+
+    //     if app.keys.down.contains(&Key::Key1) {
+    //         if app.keys.down.contains(&Key::Up) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0 + state.scale_speed, 1.0, 1.0)));
+    //         } else if app.keys.down.contains(&Key::Down) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0 - state.scale_speed, 1.0, 1.0)));
+    //         }
+    //     } else if app.keys.down.contains(&Key::Key2) {
+    //         if app.keys.down.contains(&Key::Up) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0, 1.0 + state.scale_speed, 1.0)));
+    //         } else if app.keys.down.contains(&Key::Down) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0, 1.0 - state.scale_speed, 1.0)));
+    //         }
+    //     } else if app.keys.down.contains(&Key::Key3) {
+    //         if app.keys.down.contains(&Key::Up) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0, 1.0, 1.0 + state.scale_speed)));
+    //         } else if app.keys.down.contains(&Key::Down) {
+    //             transformation = Some(AffineMat3::scale(Vec3::new(1.0, 1.0, 1.0 - state.scale_speed)));
+    //         }
+    //     } else if app.keys.down.contains(&Key::Up) {
+    //         transformation = Some(AffineMat3::translation(&camera_transformation.transform_mat[1] * state.move_speed));
+    //     } else if app.keys.down.contains(&Key::Down) {
+    //         transformation = Some(AffineMat3::translation(&camera_transformation.transform_mat[1] * -state.move_speed));
+    //     } else if app.keys.down.contains(&Key::Right) {
+    //         transformation = Some(AffineMat3::translation(&camera_transformation.transform_mat[0] * state.move_speed));
+    //     } else if app.keys.down.contains(&Key::Left) {
+    //         transformation = Some(AffineMat3::translation(&camera_transformation.transform_mat[0] * -state.move_speed));
+    //     } else if app.keys.down.contains(&Key::I) {
+    //         transformation = Some(AffineMat3::rotation(state.rotation_speed, &Vec3::new(1.0, 0.0, 0.0)));
+    //     } else if app.keys.down.contains(&Key::O) {
+    //         transformation = Some(AffineMat3::rotation(state.rotation_speed, &Vec3::new(0.0, 1.0, 0.0)));
+    //     } else if app.keys.down.contains(&Key::P) {
+    //         transformation = Some(AffineMat3::rotation(state.rotation_speed, &Vec3::new(0.0, 0.0, 1.0)));
+    //     }
+
+    //     if transformation.is_some() {
+    //         state.opts.transformations[idx] = &state.opts.transformations[idx] * &transformation.unwrap();
+    //     }
+    // }
+}
+
+
+fn process_mouse_events(app: &App, state: &mut State, event: Event) {
+    match event {
+        Event::WindowEvent {id: _, simple: window_event } => {
+            if window_event.is_none() {
+                return;
+            }
+
+            match window_event.unwrap() {
+                MouseEntered => {
+                    println!("Mouse entered!");
+                    state.mouse_is_in_window = true;
+                    state.is_mouse_inited = false;
+                },
+                MouseExited => {
+                    state.mouse_is_in_window = false;
+                    state.is_mouse_inited = false;
+                    state.opts.selected_pixel = None;
+                    state.opts.specular_strengths = [0.0, 0.0, 0.0, 0.0, 1.0];
+                },
+                // MousePressed(button) => {
+                    // if button != MouseButton::Left {
+                    //     return;
+                    // }
+
+                    // let i = (state.curr_mouse_x + (WIDTH as f32) / 2.0) as u32;
+                    // let j = (state.curr_mouse_y + (HEIGHT as f32) / 2.0) as u32;
+
+                    // state.scene.compute_pixel(i, j, true);
+
+                    // if let Some(obj_idx) = state.scene.get_object_idx_at_pixel(i, j) {
+                    //     state.opts.selected_object_idx = Some(obj_idx);
+                    //     state.opts.specular_strengths[obj_idx] = 0.7;
+                    // } else {
+                    //     state.opts.selected_object_idx = None;
+                    //     state.opts.specular_strengths = [0.0, 0.0, 0.0, 0.0, 1.0];
+                    // }
+
+                    // dbg!(&state.opts.camera_opts.position);
+                // },
+                KeyReleased(key) => process_key_released_event(app, state, key),
+                MouseWheel(scroll_delta, _) => {
+                    match scroll_delta {
+                        MouseScrollDelta::PixelDelta(position) => {
+                            state.opts.fov += (position.y as f32) * state.scroll_speed;
+                            state.opts.fov = state.opts.fov
+                                .min(std::f32::consts::PI * 165.0 / 180.0)
+                                .max(std::f32::consts::PI * 15.0 / 180.0);
+                        }
+                        _ => {}
+                    }
+                },
+                _ => {}
+            }
+        },
+        _ => {},
+    }
+}
+
+
+fn process_mouse_move(app: &App, state: &mut State) {
+    if !state.mouse_is_in_window {
+        return;
+    }
+
+    if !state.is_mouse_inited {
+        state.curr_mouse_x = app.mouse.x;
+        state.curr_mouse_y = app.mouse.y;
+        state.is_mouse_inited = true;
+    }
+
+    let offset_x = (app.mouse.x - state.curr_mouse_x) * state.mouse_sensitivity;
+    let offset_y = (state.curr_mouse_y - app.mouse.y) * state.mouse_sensitivity;
+
+    state.curr_mouse_x = app.mouse.x;
+    state.curr_mouse_y = app.mouse.y;
+    state.opts.camera_opts.turn_by(offset_x, offset_y);
+
+    // (*app.main_window()).set_cursor_position_points(WIDTH as f32 / 2.0, HEIGHT as f32 / 2.0);
+    // state.curr_mouse_x = app.mouse.x;
+    // state.curr_mouse_y = app.mouse.y;
+}
+
+
+fn view(app: &App, state: &State, frame: Frame) {
+    frame.clear(BLACK);
+
+    let draw = app.draw();
+    let start = Instant::now();
+    let hdr_img = match render_hdr(state) {
+        Ok(hdr_img) => hdr_img,
+        Err(err) => {
+            println!("Could not render the scene: {}", err);
+            return;
+        }
+    };
+    let duration = start.elapsed();
+    println!("Rending took time: {:?}", duration);
+
+    let img = hdr_img.to_ldr(&state.opts.tone_mapping);
+    img.save("image.png").unwrap();
+
+    draw.texture(&wgpu::Texture::from_image(app, &img));
+    draw.to_frame(app, &frame).unwrap();
+}
@@ -1,23 +1,45 @@
+use std::f32::consts::PI;
+
 use crate::basics::*;
+use crate::matrix::AffineMat3;
+use crate::surface::aabb::AxisAlignedBox;
 
 #[derive(Debug, Clone, Copy)]
 pub enum ProjectionType {Parallel, Perspective}
 
+// The shape of the out-of-focus highlights (bokeh)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApertureShape {
+    Disc,
+    Polygon(u32), // Number of the diaphragm blades
+}
+
+
+// Points at `focal_distance` along the viewing direction are in focus,
+// the others are blurred proportionally to `aperture_radius`
+#[derive(Debug, Clone, Copy)]
+pub struct ThinLens {
+    pub aperture_radius: f32,
+    pub focal_distance: f32,
+    pub aperture_shape: ApertureShape,
+}
+
 #[derive(Debug, Clone)]
 pub struct Camera {
-    // Defaults in world space
+    // Everything is in world space, `direction`, `up` and `right` are orthonormal
     pub origin: Point,
     direction: Vec3,
     up: Vec3,
     right: Vec3,
     projection_type: ProjectionType,
     viewing_plane: ViewingPlane,
+    lens: Option<ThinLens>, // A pinhole camera if not given
 }
 
 
 #[derive(Debug, Clone)]
 pub struct ViewingPlane {
-    pub z: f32,
+    pub distance: f32, // From the camera origin along the viewing direction
     pub x_min: f32,
     pub x_max: f32,
     pub y_min: f32,
@@ -28,43 +50,240 @@ pub struct ViewingPlane {
 
 
 impl Camera {
+    // Looks from (0, 0, z) towards -z
     pub fn from_z_position(z: f32, fov: f32, projection_type: ProjectionType, width: u32, height: u32) -> Camera {
+        Camera::look_at(
+            &Point::new(0.0, 0.0, z),
+            &Point::new(0.0, 0.0, z - 1.0),
+            &Vec3::new(0.0, 1.0, 0.0),
+            fov,
+            projection_type,
+            width,
+            height,
+        )
+    }
+
+    // `up` only has to be non-parallel to the viewing direction, it is orthogonalized
+    pub fn look_at(eye: &Point, target: &Point, up: &Vec3, fov: f32,
+                   projection_type: ProjectionType, width: u32, height: u32) -> Camera {
+        let direction = (target - eye).normalize();
+        let right = direction.cross_product(up).normalize();
+        let up = right.cross_product(&direction);
+
         Camera {
-            origin: Point {x: 0.0, y: 0.0, z: z},
-            direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
-            up: Vec3 {x: 0.0, y: 1.0, z: 0.0},
-            right: Vec3 {x: 1.0, y: 0.0, z: 0.0},
+            origin: eye.clone(),
+            direction: direction,
+            up: up,
+            right: right,
             projection_type: projection_type,
-            viewing_plane: ViewingPlane::from_fov(fov, z, width, height),
+            viewing_plane: ViewingPlane::from_fov(fov, width, height),
+            lens: None,
+        }
+    }
+
+    pub fn with_lens(self, lens: Option<ThinLens>) -> Camera {
+        Camera {lens: lens, ..self}
+    }
+
+    pub fn has_lens(&self) -> bool {
+        self.lens.is_some()
+    }
+
+    // Rotates the camera around its viewing direction, positive angles turn the image clockwise
+    pub fn with_roll(self, roll: f32) -> Camera {
+        let (sin, cos) = roll.sin_cos();
+
+        Camera {
+            right: &(&self.right * cos) + &(&self.up * sin),
+            up: &(&self.up * cos) + &(&self.right * -sin),
+            ..self
         }
     }
 
     pub fn generate_ray(&self, i: f32, j: f32) -> Ray {
         let (u, v) = self.viewing_plane.generate_uv_coords(i, j);
-        let d = self.viewing_plane.z - self.origin.z;
 
         match self.projection_type {
             ProjectionType::Perspective => Ray {
                 // TODO: actually, we do not need to clone anything here, right?
                 origin: self.origin.clone(),
-                direction: &self.direction * (-d) + &self.right * u + &self.up * v
+                direction: &self.direction * self.viewing_plane.distance + &self.right * u + &self.up * v,
+                time: 0.0,
             },
             ProjectionType::Parallel => Ray {
                 origin: &(&self.origin + &(&self.right * u)) + &(&self.up * v),
-                direction: &self.direction * (-1.0),
+                direction: self.direction.clone(),
+                time: 0.0,
             }
         }
     }
+
+    // Same as `generate_ray`, but the ray starts from a point of the lens aperture.
+    // `lens_sample` is uniformly distributed in [0, 1)^2
+    pub fn generate_lens_ray(&self, i: f32, j: f32, lens_sample: (f32, f32)) -> Ray {
+        let ray = self.generate_ray(i, j);
+        let lens = match &self.lens {
+            Some(lens) => lens,
+            None => return ray,
+        };
+
+        // All the rays through the pixel meet at the focal plane
+        let focus_t = lens.focal_distance / ray.direction.dot_product(&self.direction);
+        let focus_point = ray.compute_point(focus_t);
+        let (lens_x, lens_y) = lens.aperture_shape.sample(lens_sample);
+        let origin = &(&ray.origin + &(&self.right * (lens_x * lens.aperture_radius))) + &(&self.up * (lens_y * lens.aperture_radius));
+
+        // Keeping the length of the pinhole ray direction, so the ray parameter has the same scale
+        Ray {
+            direction: &(&focus_point - &origin) * (1.0 / focus_t),
+            origin: origin,
+            time: ray.time,
+        }
+    }
+}
+
+
+// The pitch stays below 90 degrees, where the yaw would be undefined and the view direction
+// would be parallel to the up vector of `compute_camera`
+const MAX_PITCH: f32 = 0.5 * PI - 0.001;
+
+
+// The viewpoint which is navigated in the viewers. It is shared by the ray tracer, which builds
+// a `Camera` from it, and the rasterizer, which uses its view matrix
+#[derive(Debug, Clone)]
+pub struct CameraOptions {
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll: f32,
+    pub position: Vec3,
+}
+
+
+impl CameraOptions {
+    pub fn compute_lookat(&self) -> AffineMat3 {
+        AffineMat3::create_look_at(&self.position, self.yaw, self.pitch)
+    }
+
+    // The camera looks against the last row of the lookat rotation
+    pub fn compute_view_direction(&self) -> Vec3 {
+        -&self.compute_lookat().transform_mat[2]
+    }
+
+    // Turns the camera towards the given point, the roll is kept
+    pub fn look_at(&mut self, target: &Vec3) {
+        let direction = (&self.position + &-target).normalize();
+
+        self.pitch = direction.y.asin().clamp(-MAX_PITCH, MAX_PITCH);
+        self.yaw = direction.z.atan2(direction.x);
+    }
+
+    // The rays start one unit away from `position` along the viewing direction
+    pub fn compute_eye(&self) -> Point {
+        Point::from(&self.position + &self.compute_view_direction())
+    }
+
+    pub fn compute_camera(&self, fov: f32, projection_type: ProjectionType, width: u32, height: u32) -> Camera {
+        let eye = self.compute_eye();
+        let target = &eye + &self.compute_view_direction();
+
+        Camera::look_at(&eye, &target, &Vec3::new(0.0, 1.0, 0.0), fov, projection_type, width, height)
+            .with_roll(self.roll)
+    }
+
+    // Transforms world space into the space of the camera of `compute_camera`, which is at the origin
+    // and looks towards -z with the roll applied
+    pub fn compute_view_matrix(&self) -> AffineMat3 {
+        let eye = self.compute_eye();
+        let target = &eye + &self.compute_view_direction();
+        let view_matrix = AffineMat3::new_view_matrix(&eye, &target, &Vec3::new(0.0, 1.0, 0.0));
+
+        // Rolling the camera clockwise turns the world counterclockwise, see `Camera::with_roll`
+        &AffineMat3::rotation(-self.roll, &Vec3::new(0.0, 0.0, 1.0)) * &view_matrix
+    }
+
+    // Moves the camera forward and to the right of its viewing direction, ignoring the roll
+    pub fn move_by(&mut self, forward: f32, right: f32) {
+        let lookat = self.compute_lookat();
+
+        self.position = &(&self.position + &(&lookat.transform_mat[2] * -forward)) + &(&lookat.transform_mat[0] * right);
+    }
+
+    pub fn turn_by(&mut self, yaw: f32, pitch: f32) {
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).clamp(-MAX_PITCH, MAX_PITCH);
+    }
+
+    // Moves the camera along its viewing direction, so the bounding sphere of the given
+    // world space box fits into the field of view. The orientation is kept
+    pub fn frame_bounds(&mut self, bounds: &AxisAlignedBox, fov: f32, width: u32, height: u32) {
+        let center = bounds.compute_center();
+        let radius = (&bounds.max_corner - &bounds.min_corner).norm() * 0.5;
+        // The narrower side of the viewing plane limits the field of view
+        let viewing_plane = ViewingPlane::from_fov(fov, width, height);
+        let half_angle = (viewing_plane.x_max.min(viewing_plane.y_max) / viewing_plane.distance).atan();
+        let distance = radius / half_angle.sin();
+
+        // The rays start one unit away from `position`, see `compute_camera`
+        let view_direction = &self.compute_view_direction();
+        let eye_position = &Vec3::from(&center) + &(view_direction * -distance);
+
+        self.position = &eye_position + &(view_direction * -1.0);
+    }
+}
+
+
+
+impl ApertureShape {
+    // Maps a point of the unit square onto the aperture of radius 1 preserving the uniform density
+    pub fn sample(&self, sample: (f32, f32)) -> (f32, f32) {
+        match *self {
+            ApertureShape::Disc => {
+                // Concentric mapping, which keeps the stratification of the samples
+                let (x, y) = (2.0 * sample.0 - 1.0, 2.0 * sample.1 - 1.0);
+
+                if x == 0.0 && y == 0.0 {
+                    return (0.0, 0.0);
+                }
+
+                let (radius, angle) = if x.abs() > y.abs() {
+                    (x, PI * 0.25 * (y / x))
+                } else {
+                    (y, PI * 0.5 - PI * 0.25 * (x / y))
+                };
+
+                (radius * angle.cos(), radius * angle.sin())
+            },
+            ApertureShape::Polygon(num_blades) => {
+                // The polygon is split into triangles fanning out of the center, all of them have the same area
+                let num_blades = num_blades.max(3);
+                let blade_pos = sample.0 * num_blades as f32;
+                let blade_idx = (blade_pos.floor() as u32).min(num_blades - 1);
+                let blade_sample = blade_pos - blade_idx as f32;
+                let angle_a = 2.0 * PI * blade_idx as f32 / num_blades as f32;
+                let angle_b = 2.0 * PI * (blade_idx + 1) as f32 / num_blades as f32;
+                let dist = blade_sample.sqrt();
+
+                // Uniform sampling of the triangle (center, vertex a, vertex b)
+                let weight_a = dist * (1.0 - sample.1);
+                let weight_b = dist * sample.1;
+
+                (
+                    weight_a * angle_a.cos() + weight_b * angle_b.cos(),
+                    weight_a * angle_a.sin() + weight_b * angle_b.sin(),
+                )
+            },
+        }
+    }
 }
 
 
 impl ViewingPlane {
-    pub fn from_fov(fov: f32, z: f32, width: u32, height: u32) -> ViewingPlane {
+    pub fn from_fov(fov: f32, width: u32, height: u32) -> ViewingPlane {
         let y_half = (fov * 0.5).tanh();
         let x_half = y_half * (width as f32) / (height as f32);
 
         ViewingPlane {
-            z: z + 1.0 / (fov * 0.5).tanh(),
+            distance: 1.0 / (fov * 0.5).tanh(),
             x_min: -x_half,
             x_max: x_half,
             y_min: -y_half,
@@ -104,4 +323,101 @@ mod tests {
     //     assert_eq!(vp.generate_uv_coords(320.0, 240.0), (0.0031249523, 0.0031249523));
     //     assert_eq!(vp.generate_uv_coords(640.0, 480.0), (2.0031252, 1.503125));
     // }
+
+    #[test]
+    fn test_look_at() {
+        let eye = Point::new(1.0, 2.0, 3.0);
+        let camera = Camera::look_at(&eye, &Point::new(1.0, 2.0, 8.0), &Vec3::new(0.0, 1.0, 0.0),
+                                     std::f32::consts::PI * 0.5, ProjectionType::Perspective, 64, 48);
+        let center_ray = camera.generate_ray(32.0, 24.0);
+
+        // The central ray goes from the eye straight to the target
+        assert_eq!(center_ray.origin.x, eye.x);
+        assert!(approx_eq!(f32, center_ray.direction.normalize().z, 1.0));
+        // The top row of pixels looks up
+        assert!(camera.generate_ray(32.0, 48.0).direction.y > 0.0);
+
+        // Rolling by 90 degrees turns the up vector of the image into the horizontal one
+        let rolled = camera.with_roll(std::f32::consts::PI * 0.5).generate_ray(32.0, 48.0).direction;
+        assert!(approx_eq!(f32, rolled.y, 0.0, epsilon = 0.00001));
+        assert!(rolled.x.abs() > 0.5);
+    }
+
+    #[test]
+    fn test_camera_options() {
+        let mut camera_opts = CameraOptions {pitch: 0.3, yaw: -1.2, roll: 0.4, position: Vec3::new(1.0, -2.0, 3.0)};
+        let fov = PI / 3.0;
+        let camera = camera_opts.compute_camera(fov, ProjectionType::Perspective, 64, 48);
+        let view_matrix = camera_opts.compute_view_matrix();
+        let (u, v) = ViewingPlane::from_fov(fov, 64, 48).generate_uv_coords(10.0, 40.0);
+
+        // The view matrix projects the points of a ray onto the pixel it was generated for
+        let ray = camera.generate_ray(10.0, 40.0);
+        let point_camera = &view_matrix * &ray.compute_point(3.0);
+        let distance = 1.0 / (fov * 0.5).tanh();
+        assert!(approx_eq!(f32, point_camera.x / -point_camera.z, u / distance, epsilon = 0.00001));
+        assert!(approx_eq!(f32, point_camera.y / -point_camera.z, v / distance, epsilon = 0.00001));
+
+        let eye = camera_opts.compute_eye();
+        camera_opts.move_by(2.0, 0.0);
+        let moved_eye = camera_opts.compute_eye();
+        assert!(approx_eq!(f32, (&moved_eye - &eye).dot_product(&camera_opts.compute_view_direction()), 2.0, epsilon = 0.00001));
+
+        camera_opts.turn_by(0.0, PI);
+        assert!(camera_opts.pitch < 0.5 * PI);
+
+        // Looking straight down still gives valid rays
+        camera_opts.look_at(&(&camera_opts.position + &Vec3::new(0.0, -5.0, 0.0)));
+        let ray = camera_opts.compute_camera(fov, ProjectionType::Perspective, 64, 48).generate_ray(10.0, 40.0);
+        assert!(camera_opts.pitch.abs() < 0.5 * PI);
+        assert!(ray.direction.x.is_finite() && ray.direction.y < -0.5);
+    }
+
+    #[test]
+    fn test_frame_bounds() {
+        let mut camera_opts = CameraOptions {pitch: -0.4, yaw: 0.7, roll: 0.0, position: Vec3::zero()};
+        let bounds = AxisAlignedBox {min_corner: Point::new(-1.0, 0.0, -3.0), max_corner: Point::new(2.0, 1.0, 1.0)};
+        let fov = PI * 0.5;
+        let viewing_plane = ViewingPlane::from_fov(fov, 48, 64);
+
+        camera_opts.frame_bounds(&bounds, fov, 48, 64);
+        let view_matrix = camera_opts.compute_view_matrix();
+
+        // All the corners of the box are projected inside the viewing plane
+        for corner_idx in 0..8 {
+            let corner = Point::new(
+                if corner_idx & 1 == 0 { bounds.min_corner.x } else { bounds.max_corner.x },
+                if corner_idx & 2 == 0 { bounds.min_corner.y } else { bounds.max_corner.y },
+                if corner_idx & 4 == 0 { bounds.min_corner.z } else { bounds.max_corner.z },
+            );
+            let point_camera = &view_matrix * &corner;
+            let u = point_camera.x / -point_camera.z * viewing_plane.distance;
+            let v = point_camera.y / -point_camera.z * viewing_plane.distance;
+
+            assert!(point_camera.z < 0.0);
+            assert!(viewing_plane.x_min <= u && u <= viewing_plane.x_max);
+            assert!(viewing_plane.y_min <= v && v <= viewing_plane.y_max);
+        }
+    }
+
+    #[test]
+    fn test_thin_lens() {
+        let lens = ThinLens {aperture_radius: 0.5, focal_distance: 4.0, aperture_shape: ApertureShape::Polygon(6)};
+        let camera = Camera::from_z_position(0.0, PI / 3.0, ProjectionType::Perspective, 64, 48).with_lens(Some(lens));
+        let pinhole_ray = camera.generate_ray(10.0, 20.0);
+
+        for lens_sample in &[(0.0, 0.0), (0.3, 0.9), (0.99, 0.5)] {
+            let ray = camera.generate_lens_ray(10.0, 20.0, *lens_sample);
+            // The ray starts inside the aperture, but hits the focal plane at the same point as the pinhole one
+            assert!(ray.origin.x.hypot(ray.origin.y) <= 0.5 + 0.00001);
+            let focus_x = ray.compute_point(-4.0 / ray.direction.z).x;
+            assert!(approx_eq!(f32, focus_x, pinhole_ray.compute_point(-4.0 / pinhole_ray.direction.z).x, epsilon = 0.0001));
+        }
+
+        // The disc mapping stays within the unit circle
+        for sample in &[(0.0, 0.0), (1.0, 0.5), (0.25, 0.75)] {
+            let (x, y) = ApertureShape::Disc.sample(*sample);
+            assert!(x.hypot(y) <= 1.0 + 0.00001);
+        }
+    }
 }
@@ -0,0 +1,229 @@
+// Clipping of triangles against the view frustum in homogeneous clip space, before the
+// perspective division. A vertex is inside the frustum if -w <= x, y, z <= w, so the near
+// plane is at z = -w and the far plane at z = w. Clipping happens before the division by `w`,
+// which is why triangles crossing the camera plane (w <= 0) are handled correctly.
+use crate::basics::{Color, Point, Vec3};
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipPosition {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+
+// Values which are linearly interpolated between the vertices, when clipping creates new ones
+// and over the rasterized triangles
+pub trait Interpolate: Sized {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+
+    // The weighted sum of the values of the triangle vertices, the weights sum up to one
+    fn interpolate_barycentric(values: [&Self; 3], weights: (f32, f32, f32)) -> Self {
+        let first_two_weight = weights.0 + weights.1;
+        let t = if first_two_weight > 0.0 { weights.1 / first_two_weight } else { 0.0 };
+
+        values[0].interpolate(values[1], t).interpolate(values[2], weights.2)
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct ClipVertex<A> {
+    pub position: ClipPosition,
+    pub attributes: A,
+}
+
+
+// Each plane is given by the coefficients of the signed distance a * x + b * y + c * z + d * w
+static FRUSTUM_PLANES: [[f32; 4]; 6] = [
+    [1.0, 0.0, 0.0, 1.0],  // Left
+    [-1.0, 0.0, 0.0, 1.0], // Right
+    [0.0, 1.0, 0.0, 1.0],  // Bottom
+    [0.0, -1.0, 0.0, 1.0], // Top
+    [0.0, 0.0, 1.0, 1.0],  // Near
+    [0.0, 0.0, -1.0, 1.0], // Far
+];
+
+
+impl ClipPosition {
+    fn compute_plane_distance(&self, plane: &[f32; 4]) -> f32 {
+        plane[0] * self.x + plane[1] * self.y + plane[2] * self.z + plane[3] * self.w
+    }
+
+    pub fn is_inside_frustum(&self) -> bool {
+        FRUSTUM_PLANES.iter().all(|plane| self.compute_plane_distance(plane) >= 0.0)
+    }
+}
+
+
+impl Interpolate for ClipPosition {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        ClipPosition {
+            x: self.x.interpolate(&other.x, t),
+            y: self.y.interpolate(&other.y, t),
+            z: self.z.interpolate(&other.z, t),
+            w: self.w.interpolate(&other.w, t),
+        }
+    }
+}
+
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+
+impl Interpolate for Vec3 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        &(self * (1.0 - t)) + &(other * t)
+    }
+}
+
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(self.x.interpolate(&other.x, t), self.y.interpolate(&other.y, t), self.z.interpolate(&other.z, t))
+    }
+}
+
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        &(self * (1.0 - t)) + &(other * t)
+    }
+}
+
+
+impl<A: Interpolate, B: Interpolate> Interpolate for (A, B) {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        (self.0.interpolate(&other.0, t), self.1.interpolate(&other.1, t))
+    }
+}
+
+
+impl<A: Interpolate> Interpolate for ClipVertex<A> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        ClipVertex {
+            position: self.position.interpolate(&other.position, t),
+            attributes: self.attributes.interpolate(&other.attributes, t),
+        }
+    }
+}
+
+
+// Sutherland-Hodgman: keeps the part of the convex polygon on the positive side of the plane
+fn clip_polygon_by_plane<A: Interpolate + Clone>(polygon: &[ClipVertex<A>], plane: &[f32; 4]) -> Vec<ClipVertex<A>> {
+    let mut result = Vec::with_capacity(polygon.len() + 1);
+
+    for (i, curr) in polygon.iter().enumerate() {
+        let next = &polygon[(i + 1) % polygon.len()];
+        let curr_distance = curr.position.compute_plane_distance(plane);
+        let next_distance = next.position.compute_plane_distance(plane);
+
+        if curr_distance >= 0.0 {
+            result.push(curr.clone());
+        }
+
+        // The edge crosses the plane, so a new vertex is created at the intersection
+        if (curr_distance >= 0.0) != (next_distance >= 0.0) {
+            result.push(curr.interpolate(next, curr_distance / (curr_distance - next_distance)));
+        }
+    }
+
+    result
+}
+
+
+// Returns the part of the polygon inside the frustum, which is empty if it is fully outside
+pub fn clip_polygon<A: Interpolate + Clone>(polygon: Vec<ClipVertex<A>>) -> Vec<ClipVertex<A>> {
+    FRUSTUM_PLANES.iter().fold(polygon, |polygon, plane| {
+        if polygon.is_empty() { polygon } else { clip_polygon_by_plane(&polygon, plane) }
+    })
+}
+
+
+// Clips the triangle and splits the clipped polygon into a fan of triangles with the same winding
+pub fn clip_triangle<A: Interpolate + Clone>(triangle: &[ClipVertex<A>; 3]) -> Vec<[ClipVertex<A>; 3]> {
+    // Most of the triangles are either fully visible or small, so this is the common case
+    if triangle.iter().all(|vertex| vertex.position.is_inside_frustum()) {
+        return vec![triangle.clone()];
+    }
+
+    let polygon = clip_polygon(triangle.to_vec());
+
+    (2..polygon.len())
+        .map(|i| [polygon[0].clone(), polygon[i - 1].clone(), polygon[i].clone()])
+        .collect()
+}
+
+
+#[cfg(test)]
+mod clipping_tests {
+    use super::*;
+
+    fn create_vertex(x: f32, y: f32, z: f32, w: f32) -> ClipVertex<f32> {
+        // The attribute is the camera space depth, which `w` holds as well
+        ClipVertex {position: ClipPosition {x: x, y: y, z: z, w: w}, attributes: w}
+    }
+
+    #[test]
+    fn test_interpolate_barycentric() {
+        let values = [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)];
+        let result = Vec3::interpolate_barycentric([&values[0], &values[1], &values[2]], (0.2, 0.3, 0.5));
+
+        assert!(approx_eq!(f32, result.x, 0.2, epsilon = 0.00001));
+        assert!(approx_eq!(f32, result.y, 0.3, epsilon = 0.00001));
+        assert!(approx_eq!(f32, result.z, 0.5, epsilon = 0.00001));
+
+        // At the third vertex, the first two ones have no weight at all
+        let texcoords = [(0.0, 0.0), (1.0, 0.0), (0.5, 1.0)];
+        let result = <(f32, f32)>::interpolate_barycentric([&texcoords[0], &texcoords[1], &texcoords[2]], (0.0, 0.0, 1.0));
+        assert_eq!(result, (0.5, 1.0));
+    }
+
+    #[test]
+    fn test_clip_inside() {
+        let triangle = [create_vertex(0.0, 0.0, 0.0, 1.0), create_vertex(0.5, 0.0, 0.0, 1.0), create_vertex(0.0, 0.5, 0.0, 1.0)];
+        let clipped = clip_triangle(&triangle);
+
+        assert_eq!(clipped.len(), 1);
+        assert_eq!(clipped[0][1].position, triangle[1].position);
+    }
+
+    #[test]
+    fn test_clip_outside() {
+        // Behind the camera and to the right of the frustum
+        let behind = [create_vertex(0.0, 0.0, -2.0, -1.0), create_vertex(0.5, 0.0, -2.0, -1.0), create_vertex(0.0, 0.5, -3.0, -2.0)];
+        let right = [create_vertex(2.0, 0.0, 0.0, 1.0), create_vertex(3.0, 0.0, 0.0, 1.0), create_vertex(2.0, 0.5, 0.0, 1.0)];
+
+        assert!(clip_triangle(&behind).is_empty());
+        assert!(clip_triangle(&right).is_empty());
+    }
+
+    #[test]
+    fn test_clip_near_plane() {
+        // One vertex is behind the camera, so the near plane cuts two of the edges and a quad is left
+        let triangle = [create_vertex(0.0, 0.0, 1.0, 2.0), create_vertex(0.0, 0.0, -3.0, -1.0), create_vertex(0.5, 0.0, 1.0, 2.0)];
+        let clipped = clip_triangle(&triangle);
+
+        assert_eq!(clipped.len(), 2);
+
+        for vertex in clipped.iter().flatten() {
+            assert!(vertex.position.is_inside_frustum());
+            assert!(vertex.position.w > 0.0);
+            // The attributes are interpolated along with the position
+            assert!(approx_eq!(f32, vertex.attributes, vertex.position.w, epsilon = 0.00001));
+        }
+
+        // The new vertices lie on the near plane z = -w
+        let num_near_vertices = clipped.iter()
+            .flatten()
+            .filter(|vertex| approx_eq!(f32, vertex.position.z, -vertex.position.w, epsilon = 0.00001))
+            .count();
+        assert!(num_near_vertices >= 2);
+    }
+}
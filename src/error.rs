@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use image::ImageError;
+
+use crate::scene_file::SceneParseError;
+
+
+// Errors of loading the assets (meshes, textures, scene files) and setting up a scene
+#[derive(Debug)]
+pub enum RtrsError {
+    MissingFile(PathBuf),
+    Io(PathBuf, io::Error),
+    MalformedObj(PathBuf, String),
+    EmptyMesh(PathBuf),
+    SceneParse(PathBuf, SceneParseError),
+    Texture(PathBuf, ImageError),
+    InvalidSceneConfig(String),
+}
+
+
+impl RtrsError {
+    pub fn from_io(path: &Path, err: io::Error) -> RtrsError {
+        match err.kind() {
+            io::ErrorKind::NotFound => RtrsError::MissingFile(path.to_path_buf()),
+            _ => RtrsError::Io(path.to_path_buf(), err),
+        }
+    }
+
+    pub fn from_image(path: &Path, err: ImageError) -> RtrsError {
+        match err {
+            ImageError::IoError(err) => RtrsError::from_io(path, err),
+            err => RtrsError::Texture(path.to_path_buf(), err),
+        }
+    }
+}
+
+
+impl fmt::Display for RtrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RtrsError::MissingFile(path) => write!(f, "{} does not exist", path.display()),
+            RtrsError::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            RtrsError::MalformedObj(path, message) => write!(f, "{} is not a valid .obj file: {}", path.display(), message),
+            RtrsError::EmptyMesh(path) => write!(f, "{} has no triangles", path.display()),
+            RtrsError::SceneParse(path, err) => write!(f, "{}: {}", path.display(), err),
+            RtrsError::Texture(path, err) => write!(f, "could not load texture {}: {}", path.display(), err),
+            RtrsError::InvalidSceneConfig(message) => write!(f, "invalid scene: {}", message),
+        }
+    }
+}
+
+
+impl Error for RtrsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RtrsError::Io(_, err) => Some(err),
+            RtrsError::SceneParse(_, err) => Some(err),
+            RtrsError::Texture(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file() {
+        let path = Path::new("resources/missing.obj");
+        let err = RtrsError::from_io(path, io::Error::new(io::ErrorKind::NotFound, "not found"));
+
+        match &err {
+            RtrsError::MissingFile(missing_path) => assert_eq!(missing_path, path),
+            _ => panic!("Expected a missing file error, got {:?}", err),
+        }
+        assert_eq!(err.to_string(), "resources/missing.obj does not exist");
+    }
+}
@@ -0,0 +1,302 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use image::{DynamicImage, Rgb, RgbImage};
+
+use crate::basics::Color;
+use crate::tone_mapping::ToneMapping;
+
+
+// Raw radiance produced by a renderer, rows go from top to bottom
+#[derive(Debug, Clone)]
+pub struct HdrImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<Color>,
+}
+
+
+impl HdrImage {
+    pub fn new(width: u32, height: u32, pixels: Vec<Color>) -> HdrImage {
+        assert_eq!(pixels.len(), (width * height) as usize);
+
+        HdrImage {
+            width: width,
+            height: height,
+            pixels: pixels,
+        }
+    }
+
+    pub fn get_pixel(&self, x: u32, y: u32) -> &Color {
+        &self.pixels[(self.width * y + x) as usize]
+    }
+
+    pub fn to_ldr(&self, tone_mapping: &ToneMapping) -> DynamicImage {
+        let mut img = RgbImage::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                img.put_pixel(x, y, tone_mapping.apply(self.get_pixel(x, y)).into());
+            }
+        }
+
+        DynamicImage::ImageRgb8(img)
+    }
+
+    // Quantizes the values in [0, 1] as they are, without the sRGB encoding, for the images which hold data
+    pub fn to_ldr_linear(&self) -> DynamicImage {
+        let mut img = RgbImage::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let color = self.get_pixel(x, y).clamp();
+                img.put_pixel(x, y, Rgb([
+                    (color.r * u8::MAX as f32).round() as u8,
+                    (color.g * u8::MAX as f32).round() as u8,
+                    (color.b * u8::MAX as f32).round() as u8,
+                ]));
+            }
+        }
+
+        DynamicImage::ImageRgb8(img)
+    }
+
+    pub fn to_channels(&self) -> Vec<(String, Vec<f32>)> {
+        vec![
+            ("R".to_string(), self.pixels.iter().map(|c| c.r).collect()),
+            ("G".to_string(), self.pixels.iter().map(|c| c.g).collect()),
+            ("B".to_string(), self.pixels.iter().map(|c| c.b).collect()),
+        ]
+    }
+}
+
+
+pub fn is_hdr_path(path: &str) -> bool {
+    match get_extension(path).as_deref() {
+        Some("pfm") | Some("hdr") | Some("exr") => true,
+        _ => false,
+    }
+}
+
+
+// Picks the writer by the file extension
+pub fn save_hdr(path: &str, img: &HdrImage) -> io::Result<()> {
+    match get_extension(path).as_deref() {
+        Some("pfm") => write_pfm(path, img),
+        Some("hdr") => write_hdr(path, img),
+        Some("exr") => write_exr(path, img.width, img.height, &img.to_channels()),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not a .pfm, .hdr or .exr file", path))),
+    }
+}
+
+
+fn get_extension(path: &str) -> Option<String> {
+    Path::new(path).extension().map(|ext| ext.to_string_lossy().to_lowercase())
+}
+
+
+// Portable float map: a text header followed by little endian floats, rows go from bottom to top
+pub fn write_pfm(path: &str, img: &HdrImage) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    write!(writer, "PF\n{} {}\n-1.0\n", img.width, img.height)?;
+
+    for y in (0..img.height).rev() {
+        for x in 0..img.width {
+            let color = img.get_pixel(x, y);
+
+            for value in &[color.r, color.g, color.b] {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+
+// Radiance RGBE with run-length encoded scanlines (only literal runs are emitted)
+pub fn write_hdr(path: &str, img: &HdrImage) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    write!(writer, "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n", img.height, img.width)?;
+
+    // Scanlines of other widths can not be run-length encoded
+    let use_rle = img.width >= 8 && img.width < 0x8000;
+    let mut scanline = vec![[0_u8; 4]; img.width as usize];
+
+    for y in 0..img.height {
+        for x in 0..img.width {
+            scanline[x as usize] = compute_rgbe(img.get_pixel(x, y));
+        }
+
+        if !use_rle {
+            for rgbe in &scanline {
+                writer.write_all(rgbe)?;
+            }
+            continue;
+        }
+
+        writer.write_all(&[2, 2, (img.width >> 8) as u8, (img.width & 0xff) as u8])?;
+
+        for component in 0..4 {
+            for chunk in scanline.chunks(128) {
+                writer.write_all(&[chunk.len() as u8])?;
+                writer.write_all(&chunk.iter().map(|rgbe| rgbe[component]).collect::<Vec<u8>>())?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+
+fn compute_rgbe(color: &Color) -> [u8; 4] {
+    let max_value = color.r.max(color.g).max(color.b);
+
+    if max_value < 1e-32 {
+        return [0, 0, 0, 0];
+    }
+
+    // max_value = mantissa * 2^exponent, where mantissa is in [0.5, 1)
+    let mut exponent = max_value.log2().floor() as i32 + 1;
+    if max_value / 2.0_f32.powi(exponent) >= 1.0 {
+        exponent += 1;
+    }
+    let scale = 256.0 / 2.0_f32.powi(exponent);
+
+    [
+        (color.r.max(0.0) * scale).min(255.0) as u8,
+        (color.g.max(0.0) * scale).min(255.0) as u8,
+        (color.b.max(0.0) * scale).min(255.0) as u8,
+        (exponent + 128).clamp(0, 255) as u8,
+    ]
+}
+
+
+// Uncompressed scanline OpenEXR with 32-bit float channels. Channel names may contain
+// layer prefixes (e.g. `normal.X`), they are stored in the alphabetical order as required
+pub fn write_exr(path: &str, width: u32, height: u32, channels: &[(String, Vec<f32>)]) -> io::Result<()> {
+    let mut channels = channels.iter().collect::<Vec<&(String, Vec<f32>)>>();
+    channels.sort_by(|c1, c2| c1.0.cmp(&c2.0));
+
+    for (name, values) in &channels {
+        if values.len() != (width * height) as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Channel {} has a wrong size", name)));
+        }
+    }
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&[0x76, 0x2f, 0x31, 0x01]); // Magic number
+    header.extend_from_slice(&2_i32.to_le_bytes()); // Version 2, single part scanline image
+
+    let mut channel_list = Vec::new();
+    for (name, _) in &channels {
+        channel_list.extend_from_slice(name.as_bytes());
+        channel_list.push(0);
+        channel_list.extend_from_slice(&2_i32.to_le_bytes()); // FLOAT pixel type
+        channel_list.extend_from_slice(&[0, 0, 0, 0]); // pLinear + reserved
+        channel_list.extend_from_slice(&1_i32.to_le_bytes()); // x sampling
+        channel_list.extend_from_slice(&1_i32.to_le_bytes()); // y sampling
+    }
+    channel_list.push(0);
+
+    let mut window = Vec::new();
+    for value in &[0, 0, width as i32 - 1, height as i32 - 1] {
+        window.extend_from_slice(&value.to_le_bytes());
+    }
+
+    write_exr_attribute(&mut header, "channels", "chlist", &channel_list);
+    write_exr_attribute(&mut header, "compression", "compression", &[0]);
+    write_exr_attribute(&mut header, "dataWindow", "box2i", &window);
+    write_exr_attribute(&mut header, "displayWindow", "box2i", &window);
+    write_exr_attribute(&mut header, "lineOrder", "lineOrder", &[0]);
+    write_exr_attribute(&mut header, "pixelAspectRatio", "float", &1.0_f32.to_le_bytes());
+    write_exr_attribute(&mut header, "screenWindowCenter", "v2f", &[0; 8]);
+    write_exr_attribute(&mut header, "screenWindowWidth", "float", &1.0_f32.to_le_bytes());
+    header.push(0);
+
+    // Every scanline block has its y coordinate, data size and then the data itself
+    let scanline_size = channels.len() * width as usize * 4;
+    let table_size = height as usize * 8;
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    writer.write_all(&header)?;
+
+    for y in 0..(height as usize) {
+        let offset = header.len() + table_size + y * (8 + scanline_size);
+        writer.write_all(&(offset as u64).to_le_bytes())?;
+    }
+
+    for y in 0..(height as usize) {
+        writer.write_all(&(y as i32).to_le_bytes())?;
+        writer.write_all(&(scanline_size as i32).to_le_bytes())?;
+
+        for (_, values) in &channels {
+            for value in &values[(y * width as usize)..((y + 1) * width as usize)] {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+
+fn write_exr_attribute(header: &mut Vec<u8>, name: &str, attr_type: &str, value: &[u8]) {
+    header.extend_from_slice(name.as_bytes());
+    header.push(0);
+    header.extend_from_slice(attr_type.as_bytes());
+    header.push(0);
+    header.extend_from_slice(&(value.len() as i32).to_le_bytes());
+    header.extend_from_slice(value);
+}
+
+
+#[cfg(test)]
+mod image_io_tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn create_test_image() -> HdrImage {
+        let pixels = (0..12).map(|i| Color::new(i as f32, 0.5, 2.0)).collect();
+
+        HdrImage::new(4, 3, pixels)
+    }
+
+    #[test]
+    fn test_write_pfm() {
+        let path = env::temp_dir().join("rtrs_test.pfm");
+        write_pfm(path.to_str().unwrap(), &create_test_image()).unwrap();
+
+        let data = fs::read(&path).unwrap();
+        let header = b"PF\n4 3\n-1.0\n";
+        assert_eq!(&data[..header.len()], header);
+        assert_eq!(data.len(), header.len() + 4 * 3 * 3 * 4);
+
+        // The first stored pixel is the bottom left one
+        let first_value = f32::from_le_bytes([data[header.len()], data[header.len() + 1], data[header.len() + 2], data[header.len() + 3]]);
+        assert_eq!(first_value, 8.0);
+    }
+
+    #[test]
+    fn test_compute_rgbe() {
+        assert_eq!(compute_rgbe(&Color::zero()), [0, 0, 0, 0]);
+        assert_eq!(compute_rgbe(&Color::new(1.0, 0.5, 0.0)), [128, 64, 0, 129]);
+        assert_eq!(compute_rgbe(&Color::new(0.25, 0.0, 0.0)), [128, 0, 0, 127]);
+    }
+
+    #[test]
+    fn test_write_exr() {
+        let path = env::temp_dir().join("rtrs_test.exr");
+        let img = create_test_image();
+        write_exr(path.to_str().unwrap(), img.width, img.height, &img.to_channels()).unwrap();
+
+        let data = fs::read(&path).unwrap();
+        assert_eq!(&data[..4], &[0x76, 0x2f, 0x31, 0x01]);
+
+        // The last scanline ends with the R channel of the bottom right pixel
+        let n = data.len();
+        assert_eq!(f32::from_le_bytes([data[n - 4], data[n - 3], data[n - 2], data[n - 1]]), 11.0);
+    }
+}
@@ -0,0 +1,31 @@
+// The renderer as a library. The geometry (`basics`, `matrix`, `surface`), the `camera` and
+// the `scene` are enough to trace rays, while `clipping`, `tiling`, `pipeline` and `shaders` make up
+// the rasterizer. The other modules load assets and save the images.
+// The interactive viewers of `src/bin` are only built with the `viewer` feature, so the
+// library itself does not depend on nannou and any windowing system
+extern crate rayon;
+extern crate image;
+extern crate derive_more;
+#[macro_use]
+extern crate itertools;
+#[cfg_attr(test, macro_use)]
+extern crate float_cmp;
+extern crate tobj;
+extern crate rand;
+
+pub mod basics;
+pub mod matrix;
+pub mod camera;
+pub mod surface;
+pub mod scene;
+pub mod ray_tracer;
+pub mod scene_file;
+pub mod tone_mapping;
+pub mod image_io;
+pub mod aov;
+pub mod texture;
+pub mod error;
+pub mod clipping;
+pub mod tiling;
+pub mod pipeline;
+pub mod shaders;
@@ -1,27 +1,389 @@
-extern crate rayon;
-extern crate nannou;
-extern crate derive_more;
-#[macro_use]
-extern crate itertools;
-#[macro_use]
-extern crate float_cmp;
-extern crate tobj;
-extern crate rand;
-
-mod scene;
-mod camera;
-mod basics;
-mod surface;
-mod matrix;
-mod ray_tracer;
-mod rasterizer;
+// The command line tool of the headless renderer, see `src/bin` for the interactive viewers
+use std::env;
+use std::process;
+use std::time::Instant;
+use std::str::FromStr;
+use std::path::Path;
+
+use rtrs::ray_tracer;
+use rtrs::scene_file::SceneDescription;
+use rtrs::basics::Integrator;
+use rtrs::camera::{ThinLens, ApertureShape};
+use rtrs::tone_mapping::{ToneMapping, ToneMappingOperator};
+use rtrs::image_io::{self, HdrImage};
+use rtrs::aov::{self, Aov};
+use rtrs::surface::mesh::TriangleMesh;
+use rtrs::surface::surface::VisualData;
+use rtrs::surface::bvh::{BvhBuildOptions, BvhSplitMethod};
+use rtrs::error::RtrsError;
+
+
+static USAGE: &str = "Usage:
+    rtrs render <output> [opts]   Render a single frame without opening a window. The radiance is
+                                  saved without tone mapping if <output> is a .pfm, .hdr or .exr file
+    rtrs bvh-stats <obj> [opts]   Build the BVH of a mesh and print its statistics
+
+Render options:
+    --width <W>        Image width in pixels (default: 1280)
+    --height <H>       Image height in pixels (default: 960)
+    --samples <N>      Use N x N distributed rays per pixel (default: 1)
+    --scene <IDX>      Scene to render: 0 (spheres), 1 (teapot), 2 (teaset) (default: 0)
+    --scene-file <F>   Render the scene described in the given scene file instead
+    --frame            Move the camera so all the finite objects of the scene are visible
+    --soft-shadows     Sample the area light for soft shadows
+    --aperture <R>     Lens aperture radius for depth of field, requires --focus (default: a pinhole)
+    --focus <D>        Distance to the plane in focus along the viewing direction, requires --aperture
+    --blades <N>       Use a polygonal aperture with N blades instead of a disc, requires --aperture
+    --motion-blur      Blur the objects which move while the shutter is open
+    --time <T>         Time of the shutter opening in seconds, which places the flying spheres of scene 0
+                       (default: 0 with --motion-blur, otherwise the spheres stay at their initial places)
+    --integrator <I>   Either `whitted` or `path` (path tracing) (default: whitted)
+    --tone-mapping <T> Either `clamp`, `reinhard` or `aces` (default: clamp)
+    --exposure <EV>    Exposure adjustment in stops (default: 0)
+    --aovs <LIST>      Comma separated AOVs to render as well: depth, normal, object_id, albedo,
+                       diffuse, specular, reflection or `all`. They are stored as layers of an
+                       .exr output or saved next to the output as <name>.<aov>.<ext>
+
+BVH statistics options:
+    --split <S>        Either `median` or `sah`, both builders are compared if not given
+    --leaf-size <N>    Maximum number of triangles in a leaf (default: 4)
+    --bins <N>         Number of bins of the SAH builder (default: 16)";
+
+
+#[derive(Debug, Clone)]
+struct RenderArgs {
+    output_path: String,
+    width: u32,
+    height: u32,
+    num_samples: u32,
+    scene_idx: u32,
+    scene_file: Option<String>,
+    frame_scene: bool,
+    use_soft_shadows: bool,
+    lens: Option<ThinLens>,
+    use_motion_blur: bool,
+    time: Option<f32>,
+    integrator: Integrator,
+    tone_mapping_operator: ToneMappingOperator,
+    exposure: f32,
+    aovs: Vec<Aov>,
+}
+
+
+impl RenderArgs {
+    fn from_args(args: &[String]) -> Result<RenderArgs, String> {
+        let mut render_args = RenderArgs {
+            output_path: String::new(),
+            width: 1280,
+            height: 960,
+            num_samples: 1,
+            scene_idx: 0,
+            scene_file: None,
+            frame_scene: false,
+            use_soft_shadows: false,
+            lens: None,
+            use_motion_blur: false,
+            time: None,
+            integrator: Integrator::Whitted,
+            tone_mapping_operator: ToneMappingOperator::Clamp,
+            exposure: 0.0,
+            aovs: Vec::new(),
+        };
+        let mut args_iter = args.iter();
+        let mut aperture_radius: Option<f32> = None;
+        let mut focal_distance: Option<f32> = None;
+        let mut aperture_shape = ApertureShape::Disc;
+
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--width" => render_args.width = parse_value(arg, args_iter.next())?,
+                "--height" => render_args.height = parse_value(arg, args_iter.next())?,
+                "--samples" => render_args.num_samples = parse_value(arg, args_iter.next())?,
+                "--scene" => render_args.scene_idx = parse_value(arg, args_iter.next())?,
+                "--scene-file" => render_args.scene_file = Some(args_iter.next()
+                    .ok_or(format!("Option {} requires a value", arg))?
+                    .clone()),
+                "--frame" => render_args.frame_scene = true,
+                "--soft-shadows" => render_args.use_soft_shadows = true,
+                "--aperture" => aperture_radius = Some(parse_value(arg, args_iter.next())?),
+                "--focus" => focal_distance = Some(parse_value(arg, args_iter.next())?),
+                "--blades" => aperture_shape = ApertureShape::Polygon(parse_value(arg, args_iter.next())?),
+                "--motion-blur" => render_args.use_motion_blur = true,
+                "--time" => render_args.time = Some(parse_value(arg, args_iter.next())?),
+                "--integrator" => render_args.integrator = match args_iter.next().map(|v| v.as_str()) {
+                    Some("whitted") => Integrator::Whitted,
+                    Some("path") => Integrator::PathTracing,
+                    _ => return Err(format!("Option {} requires either `whitted` or `path`", arg)),
+                },
+                "--tone-mapping" => render_args.tone_mapping_operator = match args_iter.next().map(|v| v.as_str()) {
+                    Some("clamp") => ToneMappingOperator::Clamp,
+                    Some("reinhard") => ToneMappingOperator::Reinhard,
+                    Some("aces") => ToneMappingOperator::AcesFilmic,
+                    _ => return Err(format!("Option {} requires either `clamp`, `reinhard` or `aces`", arg)),
+                },
+                "--exposure" => render_args.exposure = parse_value(arg, args_iter.next())?,
+                "--aovs" => render_args.aovs = parse_aovs(arg, args_iter.next())?,
+                _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+                _ if render_args.output_path.is_empty() => render_args.output_path = arg.clone(),
+                _ => return Err(format!("Unexpected argument: {}", arg)),
+            }
+        }
+
+        if render_args.output_path.is_empty() {
+            return Err("An output image path is required".to_string());
+        }
+
+        if render_args.width == 0 || render_args.height == 0 || render_args.num_samples == 0 {
+            return Err("Width, height and the number of samples must be positive".to_string());
+        }
+
+        if render_args.scene_idx >= ray_tracer::NUM_SCENES {
+            return Err(format!("Scene index must be less than {}", ray_tracer::NUM_SCENES));
+        }
+
+        if let ApertureShape::Polygon(num_blades) = aperture_shape {
+            if num_blades < 3 {
+                return Err("The aperture must have at least 3 blades".to_string());
+            }
+        }
+
+        if let Some(radius) = aperture_radius.filter(|radius| *radius <= 0.0 || radius.is_nan()) {
+            return Err(format!("The aperture radius must be positive, got {}", radius));
+        }
+
+        if let Some(distance) = focal_distance.filter(|distance| *distance <= 0.0 || distance.is_nan()) {
+            return Err(format!("The focus distance must be positive, got {}", distance));
+        }
+
+        render_args.lens = match (aperture_radius, focal_distance) {
+            (Some(aperture_radius), Some(focal_distance)) => Some(ThinLens {
+                aperture_radius: aperture_radius,
+                focal_distance: focal_distance,
+                aperture_shape: aperture_shape,
+            }),
+            (Some(_), None) => return Err("Option --aperture requires --focus".to_string()),
+            (None, Some(_)) => return Err("Option --focus requires --aperture".to_string()),
+            (None, None) if aperture_shape != ApertureShape::Disc => {
+                return Err("Option --blades requires --aperture".to_string());
+            },
+            (None, None) => None,
+        };
+
+        Ok(render_args)
+    }
+}
+
+
+fn parse_value<T: FromStr>(option: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("Option {} requires a value", option))?;
+
+    value.parse::<T>().map_err(|_| format!("Invalid value for {}: {}", option, value))
+}
+
+
+fn parse_aovs(option: &str, value: Option<&String>) -> Result<Vec<Aov>, String> {
+    let value = value.ok_or(format!("Option {} requires a value", option))?;
+
+    if value == "all" {
+        return Ok(Aov::all().to_vec());
+    }
+
+    value
+        .split(',')
+        .map(|name| Aov::from_name(name.trim()).ok_or(format!("Unknown AOV for {}: {}", option, name)))
+        .collect()
+}
+
+
+fn save_image(path: &str, img: &HdrImage, tone_mapping: &ToneMapping) -> Result<(), String> {
+    if image_io::is_hdr_path(path) {
+        image_io::save_hdr(path, img).map_err(|err| err.to_string())
+    } else {
+        img.to_ldr(tone_mapping).save(path).map_err(|err| err.to_string())
+    }
+}
+
+
+// Either adds the AOVs as layers of the output EXR or saves them as separate images
+fn save_image_with_aovs(path: &str, img: &HdrImage, aov_images: &[(Aov, HdrImage)], tone_mapping: &ToneMapping) -> Result<(), String> {
+    if path.to_lowercase().ends_with(".exr") {
+        return aov::write_layered_exr(path, img, aov_images).map_err(|err| err.to_string());
+    }
+
+    save_image(path, img, tone_mapping)?;
+
+    let path = Path::new(path);
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+
+    for (aov, aov_img) in aov_images {
+        let aov_path = path.with_file_name(format!("{}.{}.{}", stem, aov.name(), extension));
+        let aov_path = aov_path.to_string_lossy();
+
+        if image_io::is_hdr_path(&aov_path) {
+            save_image(&aov_path, aov_img, tone_mapping)?;
+        } else {
+            aov::create_aov_ldr_image(*aov, aov_img).save(aov_path.as_ref()).map_err(|err| err.to_string())?;
+        }
+
+        println!("Saved the {} AOV to {}", aov.name(), aov_path);
+    }
+
+    Ok(())
+}
+
+
+// A failed render of a batch job is reported via the exit code
+fn unwrap_or_exit<T>(result: Result<T, RtrsError>, message: &str) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}: {}", message, err);
+        process::exit(1);
+    })
+}
+
+
+fn render_headless(render_args: &RenderArgs) {
+    let mut state = unwrap_or_exit(ray_tracer::init_state(), "Could not build the state");
+    state.selected_scene_idx = render_args.scene_idx;
+    state.opts.width = render_args.width;
+    state.opts.height = render_args.height;
+    state.opts.num_dist_rt_samples = render_args.num_samples;
+    state.opts.use_supersampling = render_args.num_samples > 1;
+    state.opts.use_soft_shadows = render_args.use_soft_shadows;
+    state.opts.use_motion_blur = render_args.use_motion_blur;
+
+    if render_args.use_motion_blur || render_args.time.is_some() {
+        state.opts.update_transformations_on_time(render_args.time.unwrap_or(0.0));
+    }
+    state.opts.integrator = render_args.integrator;
+    state.opts.tone_mapping.operator = render_args.tone_mapping_operator;
+    state.opts.tone_mapping.exposure = render_args.exposure;
+
+    if let Some(scene_file) = &render_args.scene_file {
+        match SceneDescription::from_file(scene_file) {
+            Ok(scene_description) => state.set_scene_description(scene_description),
+            Err(err) => {
+                eprintln!("Could not load the scene: {}", err);
+                process::exit(1);
+            }
+        }
+    }
+
+    // The lens of the command line overrides the one of the scene file
+    if render_args.lens.is_some() {
+        state.opts.lens = render_args.lens;
+    }
+
+    if render_args.frame_scene {
+        unwrap_or_exit(state.frame_scene(), "Could not frame the scene");
+    }
+
+    let start = Instant::now();
+    let save_result = if render_args.aovs.is_empty() {
+        let hdr_img = unwrap_or_exit(ray_tracer::render_hdr(&state), "Could not render the scene");
+        println!("Rendering took time: {:?}", start.elapsed());
+
+        save_image(&render_args.output_path, &hdr_img, &state.opts.tone_mapping)
+    } else {
+        let (hdr_img, aov_images) = unwrap_or_exit(ray_tracer::render_hdr_with_aovs(&state), "Could not render the scene");
+        println!("Rendering took time: {:?}", start.elapsed());
+
+        let aov_images = aov_images
+            .into_iter()
+            .filter(|(aov, _)| render_args.aovs.contains(aov))
+            .collect::<Vec<(Aov, HdrImage)>>();
+
+        save_image_with_aovs(&render_args.output_path, &hdr_img, &aov_images, &state.opts.tone_mapping)
+    };
+
+    if let Err(err) = save_result {
+        eprintln!("Could not save the image to {}: {}", render_args.output_path, err);
+        process::exit(1);
+    }
+
+    println!("Saved the image to {}", render_args.output_path);
+}
+
+
+fn print_bvh_stats(args: &[String]) -> Result<(), String> {
+    let mut obj_file = None;
+    let mut split_methods = vec![BvhSplitMethod::Median, BvhSplitMethod::Sah];
+    let mut options = BvhBuildOptions::defaults();
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--split" => split_methods = match args_iter.next().map(|v| v.as_str()) {
+                Some("median") => vec![BvhSplitMethod::Median],
+                Some("sah") => vec![BvhSplitMethod::Sah],
+                _ => return Err(format!("Option {} requires either `median` or `sah`", arg)),
+            },
+            "--leaf-size" => options.max_leaf_size = parse_value(arg, args_iter.next())?,
+            "--bins" => options.num_bins = parse_value(arg, args_iter.next())?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ if obj_file.is_none() => obj_file = Some(arg.clone()),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    let obj_file = obj_file.ok_or("An .obj file is required".to_string())?;
+    let mut mesh = TriangleMesh::from_obj(&obj_file, VisualData::grey()).map_err(|err| err.to_string())?;
+
+    for split_method in split_methods {
+        options.split_method = split_method;
+
+        let start = Instant::now();
+        let stats = mesh.build_bvh(&options);
+        println!("{:?} builder (took {:?}):\n{}\n", split_method, start.elapsed(), stats);
+    }
+
+    Ok(())
+}
 
 
 fn main() {
     // rayon::ThreadPoolBuilder::new().num_threads(8).build_global().unwrap();
     // rayon::ThreadPoolBuilder::new().num_threads(16).build_global().unwrap();
+    let args = env::args().skip(1).collect::<Vec<String>>();
 
-    ray_tracer::launch();
-    // rasterizer::launch();
+    match args.first().map(|arg| arg.as_str()) {
+        Some("render") => match RenderArgs::from_args(&args[1..]) {
+            Ok(render_args) => render_headless(&render_args),
+            Err(err) => {
+                eprintln!("{}\n\n{}", err, USAGE);
+                process::exit(1);
+            }
+        },
+        Some("bvh-stats") => if let Err(err) = print_bvh_stats(&args[1..]) {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(1);
+        },
+        Some("--help") | Some("-h") => println!("{}", USAGE),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        },
+    }
 }
 
+
+#[cfg(test)]
+mod main_tests {
+    use super::*;
+
+    fn parse_render_args(args: &str) -> Result<RenderArgs, String> {
+        RenderArgs::from_args(&args.split_whitespace().map(|arg| arg.to_string()).collect::<Vec<String>>())
+    }
+
+    #[test]
+    fn test_lens_args() {
+        assert!(parse_render_args("out.png --aperture 0.1 --focus 3 --blades 6").unwrap().lens.is_some());
+        assert!(parse_render_args("out.png").unwrap().lens.is_none());
+
+        for args in &["--aperture 0 --focus 3", "--aperture -0.1 --focus 3", "--aperture NaN --focus 3",
+                      "--aperture 0.1 --focus 0", "--aperture 0.1 --focus -3", "--aperture 0.1",
+                      "--focus 3", "--blades 6"] {
+            assert!(parse_render_args(&format!("out.png {}", args)).is_err(), "{} should be rejected", args);
+        }
+    }
+}
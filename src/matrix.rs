@@ -79,6 +79,53 @@ impl Mat3 {
             Vec3::new(self[0][2], self[1][2], self[2][2]),
         ]}
     }
+
+    // Square root of the sum of the squared elements
+    pub fn compute_frobenius_norm(&self) -> f32 {
+        self.rows.iter().map(|row| row.norm_squared()).sum::<f32>().sqrt()
+    }
+
+    // Splits the matrix into a rotation and a stretch (symmetric) matrix: M = R * S
+    pub fn polar_decomposition(&self) -> (Mat3, Mat3) {
+        let mut rotation = self.clone();
+
+        // Averaging the matrix with its inverse transpose converges to the closest orthogonal one
+        for _ in 0..100 {
+            let next_rotation = &(&rotation + &rotation.compute_inverse().transpose()) * 0.5;
+            let max_diff = (0..3)
+                .flat_map(|i| (0..3).map(move |j| (i, j)))
+                .map(|(i, j)| (next_rotation[i][j] - rotation[i][j]).abs())
+                .fold(0.0, f32::max);
+
+            rotation = next_rotation;
+
+            if max_diff < 0.000001 {
+                break;
+            }
+        }
+
+        // Reflections are moved into the stretch, so the rotation can be turned into a quaternion
+        if rotation.det() < 0.0 {
+            rotation = &rotation * -1.0;
+        }
+
+        let stretch = &rotation.transpose() * self;
+
+        (rotation, stretch)
+    }
+}
+
+
+impl ops::Add<&Mat3> for &Mat3 {
+    type Output = Mat3;
+
+    fn add(self, other: &Mat3) -> Mat3 {
+        Mat3 {rows: [
+            &self.rows[0] + &other.rows[0],
+            &self.rows[1] + &other.rows[1],
+            &self.rows[2] + &other.rows[2],
+        ]}
+    }
 }
 
 
@@ -326,6 +373,144 @@ impl ops::Mul<&Vec3> for &DiagMat3 {
 }
 
 
+// A unit quaternion representing a rotation
+#[derive(Debug, Clone, Copy)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+
+impl Quaternion {
+    pub fn from_rotation(m: &Mat3) -> Quaternion {
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        // Choosing the largest component to divide by for numerical stability
+        if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            Quaternion {w: 0.25 / s, x: (m[2][1] - m[1][2]) * s, y: (m[0][2] - m[2][0]) * s, z: (m[1][0] - m[0][1]) * s}
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = 2.0 * (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            Quaternion {w: (m[2][1] - m[1][2]) / s, x: 0.25 * s, y: (m[0][1] + m[1][0]) / s, z: (m[0][2] + m[2][0]) / s}
+        } else if m[1][1] > m[2][2] {
+            let s = 2.0 * (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt();
+            Quaternion {w: (m[0][2] - m[2][0]) / s, x: (m[0][1] + m[1][0]) / s, y: 0.25 * s, z: (m[1][2] + m[2][1]) / s}
+        } else {
+            let s = 2.0 * (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt();
+            Quaternion {w: (m[1][0] - m[0][1]) / s, x: (m[0][2] + m[2][0]) / s, y: (m[1][2] + m[2][1]) / s, z: 0.25 * s}
+        }
+    }
+
+    pub fn to_rotation(&self) -> Mat3 {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        Mat3 {rows: [
+            Vec3::new(1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)),
+            Vec3::new(2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)),
+            Vec3::new(2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)),
+        ]}
+    }
+
+    fn dot(&self, other: &Quaternion) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn blend(&self, weight: f32, other: &Quaternion, other_weight: f32) -> Quaternion {
+        let q = Quaternion {
+            w: self.w * weight + other.w * other_weight,
+            x: self.x * weight + other.x * other_weight,
+            y: self.y * weight + other.y * other_weight,
+            z: self.z * weight + other.z * other_weight,
+        };
+        let norm = q.dot(&q).sqrt();
+
+        Quaternion {w: q.w / norm, x: q.x / norm, y: q.y / norm, z: q.z / norm}
+    }
+
+    // The angle of the rotation from `self` to `other` along the shortest arc
+    pub fn compute_angle(&self, other: &Quaternion) -> f32 {
+        2.0 * self.dot(other).abs().min(1.0).acos()
+    }
+
+    // Spherical linear interpolation along the shortest arc
+    pub fn slerp(&self, other: &Quaternion, t: f32) -> Quaternion {
+        let mut cos_theta = self.dot(other);
+        let mut other = *other;
+
+        if cos_theta < 0.0 {
+            // q and -q are the same rotation
+            other = Quaternion {w: -other.w, x: -other.x, y: -other.y, z: -other.z};
+            cos_theta = -cos_theta;
+        }
+
+        if cos_theta > 0.9995 {
+            // The quaternions are too close for the division by sin(theta)
+            return self.blend(1.0 - t, &other, t);
+        }
+
+        let theta = cos_theta.acos();
+
+        self.blend(((1.0 - t) * theta).sin() / theta.sin(), &other, (t * theta).sin() / theta.sin())
+    }
+}
+
+
+// A transformation changing in time from `start` (t = 0) to `end` (t = 1). The rotations are
+// interpolated with slerp, while the translations and the stretches are interpolated linearly
+#[derive(Debug, Clone)]
+pub struct AnimatedTransform {
+    pub start: AffineMat3,
+    pub end: AffineMat3,
+    rotations: (Quaternion, Quaternion),
+    stretches: (Mat3, Mat3),
+}
+
+
+impl AnimatedTransform {
+    pub fn new(start: AffineMat3, end: AffineMat3) -> AnimatedTransform {
+        let (start_rotation, start_stretch) = start.transform_mat.polar_decomposition();
+        let (end_rotation, end_stretch) = end.transform_mat.polar_decomposition();
+
+        AnimatedTransform {
+            rotations: (Quaternion::from_rotation(&start_rotation), Quaternion::from_rotation(&end_rotation)),
+            stretches: (start_stretch, end_stretch),
+            start: start,
+            end: end,
+        }
+    }
+
+    pub fn interpolate(&self, t: f32) -> AffineMat3 {
+        if t <= 0.0 {
+            return self.start.clone();
+        } else if t >= 1.0 {
+            return self.end.clone();
+        }
+
+        let rotation = self.rotations.0.slerp(&self.rotations.1, t).to_rotation();
+        let stretch = &(&self.stretches.0 * (1.0 - t)) + &(&self.stretches.1 * t);
+
+        AffineMat3 {
+            transform_mat: &rotation * &stretch,
+            translation: &(&self.start.translation * (1.0 - t)) + &(&self.end.translation * t),
+        }
+    }
+
+    // Upper bound of how far a point within `radius` from the origin gets from the straight line
+    // between its positions at two times `dt` apart. Only the rotation bends the paths, so with the
+    // angular speed w and f(t) = R(t) S(t) x the distance is at most dt^2 / 8 * max |f''|, where
+    // |f''| <= w^2 |S(t) x| + 2 w |S' x|
+    pub fn compute_chord_error(&self, radius: f32, dt: f32) -> f32 {
+        let angular_speed = self.rotations.0.compute_angle(&self.rotations.1);
+        let max_stretch = self.stretches.0.compute_frobenius_norm().max(self.stretches.1.compute_frobenius_norm());
+        let stretch_speed = (&self.stretches.1 + &(&self.stretches.0 * -1.0)).compute_frobenius_norm();
+
+        dt * dt / 8.0 * radius * angular_speed * (angular_speed * max_stretch + 2.0 * stretch_speed)
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -457,4 +642,28 @@ mod tests {
         assert!(approx_eq!(f32, point_rotated.y, 0.0, epsilon=0.0001));
         assert!(approx_eq!(f32, point_rotated.z, -1.0, epsilon=0.0001));
     }
+
+    #[test]
+    fn test_animated_transform() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let start = &AffineMat3::translation(Vec3::new(0.0, 2.0, 0.0)) * &AffineMat3::scale(Vec3::new(1.0, 2.0, 1.0));
+        let end = &AffineMat3::translation(Vec3::new(4.0, 2.0, 0.0))
+            * &(&AffineMat3::rotation(std::f32::consts::PI * 0.5, &axis) * &AffineMat3::scale(Vec3::new(3.0, 2.0, 3.0)));
+        let animated = AnimatedTransform::new(start, end);
+
+        // Halfway, the rotation is by 45 degrees and the scale is averaged
+        let middle = animated.interpolate(0.5);
+        let expected = &AffineMat3::translation(Vec3::new(2.0, 2.0, 0.0))
+            * &(&AffineMat3::rotation(std::f32::consts::PI * 0.25, &axis) * &AffineMat3::scale(Vec3::new(2.0, 2.0, 2.0)));
+
+        for (row, col) in iproduct!(0..3, 0..3) {
+            assert!(approx_eq!(f32, middle.transform_mat[row][col], expected.transform_mat[row][col], epsilon=0.0001));
+        }
+        assert_eq!(middle.translation, expected.translation);
+
+        let quaternion = Quaternion::from_rotation(&Mat3::rotation(1.0, &axis));
+        let rotation = quaternion.slerp(&quaternion, 0.3).to_rotation();
+        assert!(approx_eq!(f32, rotation[0][0], 1.0_f32.cos(), epsilon=0.0001));
+        assert!(approx_eq!(f32, rotation[0][2], 1.0_f32.sin(), epsilon=0.0001));
+    }
 }
@@ -0,0 +1,399 @@
+// The programmable rasterization pipeline. The vertex shader transforms the vertices of each
+// triangle into clip space and computes their varyings, then the triangles are clipped against
+// the view frustum and binned into screen tiles, which are rasterized in parallel. The varyings
+// are interpolated with perspective correction and passed to the fragment shader
+use rayon::prelude::*;
+use tobj::Mesh;
+
+use crate::basics::{Color, Point, Vec3};
+use crate::camera::ViewingPlane;
+use crate::clipping::{self, ClipPosition, ClipVertex, Interpolate};
+use crate::tiling::{Tile, TileGrid, ScreenBounds, TILE_SIZE};
+
+
+// The attributes of a mesh vertex, in the object space
+#[derive(Debug, Clone)]
+pub struct VertexInput {
+    pub position: Point,
+    pub normal: Vec3, // The face normal if the mesh has no normals
+    pub face_normal: Vec3,
+    pub texcoords: (f32, f32),
+}
+
+
+pub trait VertexShader: Sync {
+    // The values which are interpolated over the triangle for the fragment shader
+    type Varyings: Interpolate + Clone + Send + Sync;
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<Self::Varyings>;
+}
+
+
+pub trait FragmentShader<V>: Sync {
+    fn shade_fragment(&self, varyings: &V) -> Color;
+}
+
+
+// Perspective projection of the camera space, where the camera looks along -z
+#[derive(Debug, Clone)]
+pub struct Projection {
+    pub viewing_plane: ViewingPlane,
+    pub near_clipping_plane: f32,
+    pub far_clipping_plane: f32,
+}
+
+
+// The color and depth buffers of the frame or of a single tile
+#[derive(Debug, Clone)]
+pub struct FrameBuffer {
+    pub width: usize,
+    pub height: usize,
+    pub colors: Vec<Color>,
+    pub depths: Vec<f32>, // The distance along the viewing direction
+}
+
+
+// A clipped triangle which is ready to be binned and rasterized
+struct ScreenTriangle<V> {
+    positions: [Point; 3], // The depth is kept as `z`
+    varyings: [V; 3],
+}
+
+
+impl Projection {
+    // The viewing plane is mapped onto [-1, 1] after the division by `w`, while the depth goes
+    // from -1 at the near clipping plane to 1 at the far one
+    pub fn project(&self, point_camera: &Point) -> ClipPosition {
+        let depth = -point_camera.z; // Since our camera coordinate system looks "behind"
+        let (near, far) = (self.near_clipping_plane, self.far_clipping_plane);
+        let plane = &self.viewing_plane;
+
+        ClipPosition {
+            x: (2.0 * plane.distance * point_camera.x - (plane.x_max + plane.x_min) * depth) / (plane.x_max - plane.x_min),
+            y: (2.0 * plane.distance * point_camera.y - (plane.y_max + plane.y_min) * depth) / (plane.y_max - plane.y_min),
+            z: (depth * (far + near) - 2.0 * far * near) / (far - near),
+            w: depth,
+        }
+    }
+}
+
+
+impl FrameBuffer {
+    pub fn new(width: usize, height: usize, color: &Color, depth: f32) -> FrameBuffer {
+        FrameBuffer {
+            width: width,
+            height: height,
+            colors: vec![*color; width * height],
+            depths: vec![depth; width * height],
+        }
+    }
+
+    fn read_tile(&self, tile: &Tile) -> FrameBuffer {
+        let mut tile_buffer = FrameBuffer {
+            width: tile.width(),
+            height: tile.height(),
+            colors: Vec::with_capacity(tile.width() * tile.height()),
+            depths: Vec::with_capacity(tile.width() * tile.height()),
+        };
+
+        for y in tile.y_min..tile.y_max {
+            let row = (y * self.width + tile.x_min)..(y * self.width + tile.x_max);
+
+            tile_buffer.colors.extend_from_slice(&self.colors[row.clone()]);
+            tile_buffer.depths.extend_from_slice(&self.depths[row]);
+        }
+
+        tile_buffer
+    }
+
+    fn write_tile(&mut self, tile: &Tile, tile_buffer: &FrameBuffer) {
+        assert!(tile.x_max <= self.width && tile.y_max <= self.height);
+
+        for y in 0..tile.height() {
+            let src = y * tile_buffer.width;
+            let dst = (tile.y_min + y) * self.width + tile.x_min;
+
+            self.colors[dst..(dst + tile.width())].copy_from_slice(&tile_buffer.colors[src..(src + tile.width())]);
+            self.depths[dst..(dst + tile.width())].copy_from_slice(&tile_buffer.depths[src..(src + tile.width())]);
+        }
+    }
+}
+
+
+impl<V> ScreenTriangle<V> {
+    fn compute_bounds(&self) -> ScreenBounds {
+        let (v0, v1, v2) = (&self.positions[0], &self.positions[1], &self.positions[2]);
+
+        ScreenBounds {
+            x_min: v0.x.min(v1.x).min(v2.x),
+            y_min: v0.y.min(v1.y).min(v2.y),
+            x_max: v0.x.max(v1.x).max(v2.x),
+            y_max: v0.y.max(v1.y).max(v2.y),
+        }
+    }
+
+    // Twice the signed area, which is positive for the front faces
+    fn compute_signed_area(&self) -> f32 {
+        compute_edge_function(&self.positions[0], &self.positions[1], &self.positions[2])
+    }
+}
+
+
+// Draws the triangles of the mesh over the current content of the frame. Triangles facing
+// away from the camera are skipped if `cull_back_faces` is set
+pub fn draw_mesh<S>(frame: &mut FrameBuffer, mesh: &Mesh, shader: &S, cull_back_faces: bool)
+    where S: VertexShader + FragmentShader<<S as VertexShader>::Varyings> {
+
+    let (width, height) = (frame.width, frame.height);
+
+    // The triangles are shaded at the vertices and clipped in parallel
+    let triangles = (0..(mesh.indices.len() / 3))
+        .into_par_iter()
+        .flat_map(|i| setup_triangle(mesh, shader, i, width, height, cull_back_faces))
+        .collect::<Vec<ScreenTriangle<S::Varyings>>>();
+    let bounds = triangles.iter().map(|triangle| triangle.compute_bounds()).collect::<Vec<ScreenBounds>>();
+    let grid = TileGrid::new(width, height, TILE_SIZE);
+    let bins = grid.bin(&bounds);
+
+    // Each tile has its own copy of the color and depth buffers, so the tiles do not need any synchronization
+    let frame_ref = &*frame;
+    let tile_buffers = (0..grid.num_tiles())
+        .into_par_iter()
+        .filter(|tile_idx| !bins[*tile_idx].is_empty())
+        .map(|tile_idx| -> (Tile, FrameBuffer) {
+            let tile = grid.get_tile(tile_idx);
+            let mut tile_buffer = frame_ref.read_tile(&tile);
+
+            for triangle_idx in bins[tile_idx].iter() {
+                rasterize_triangle(shader, &triangles[*triangle_idx], &tile, &mut tile_buffer);
+            }
+
+            (tile, tile_buffer)
+        })
+        .collect::<Vec<(Tile, FrameBuffer)>>();
+
+    for (tile, tile_buffer) in tile_buffers.iter() {
+        frame.write_tile(tile, tile_buffer);
+    }
+}
+
+
+fn fetch_vertex(mesh: &Mesh, idx: usize, face_normal: &Vec3) -> VertexInput {
+    let normal = if mesh.normals.is_empty() {
+        face_normal.clone()
+    } else {
+        Vec3::new(mesh.normals[idx * 3], mesh.normals[idx * 3 + 1], mesh.normals[idx * 3 + 2])
+    };
+    let texcoords = if mesh.texcoords.is_empty() {
+        (0.0, 0.0)
+    } else {
+        (mesh.texcoords[idx * 2], mesh.texcoords[idx * 2 + 1])
+    };
+
+    VertexInput {
+        position: Point::new(mesh.positions[idx * 3], mesh.positions[idx * 3 + 1], mesh.positions[idx * 3 + 2]),
+        normal: normal,
+        face_normal: face_normal.clone(),
+        texcoords: texcoords,
+    }
+}
+
+
+// Shades the vertices of the i-th triangle of the mesh and clips it, which gives up to several triangles in screen space
+fn setup_triangle<S: VertexShader>(
+    mesh: &Mesh, shader: &S, i: usize, width: usize, height: usize, cull_back_faces: bool) -> Vec<ScreenTriangle<S::Varyings>> {
+
+    let indices = [mesh.indices[i * 3] as usize, mesh.indices[i * 3 + 1] as usize, mesh.indices[i * 3 + 2] as usize];
+    let positions = [
+        Point::new(mesh.positions[indices[0] * 3], mesh.positions[indices[0] * 3 + 1], mesh.positions[indices[0] * 3 + 2]),
+        Point::new(mesh.positions[indices[1] * 3], mesh.positions[indices[1] * 3 + 1], mesh.positions[indices[1] * 3 + 2]),
+        Point::new(mesh.positions[indices[2] * 3], mesh.positions[indices[2] * 3 + 1], mesh.positions[indices[2] * 3 + 2]),
+    ];
+    let face_normal = (&positions[1] - &positions[0]).cross_product(&(&positions[2] - &positions[0])).normalize();
+    let triangle = [
+        shader.shade_vertex(&fetch_vertex(mesh, indices[0], &face_normal)),
+        shader.shade_vertex(&fetch_vertex(mesh, indices[1], &face_normal)),
+        shader.shade_vertex(&fetch_vertex(mesh, indices[2], &face_normal)),
+    ];
+
+    // Triangles crossing the near plane are split, so the camera can be inside the model
+    clipping::clip_triangle(&triangle)
+        .into_iter()
+        .map(|clipped_triangle| {
+            let [c0, c1, c2] = clipped_triangle;
+
+            ScreenTriangle {
+                positions: [
+                    convert_to_screen(&c0.position, width, height),
+                    convert_to_screen(&c1.position, width, height),
+                    convert_to_screen(&c2.position, width, height),
+                ],
+                varyings: [c0.attributes, c1.attributes, c2.attributes],
+            }
+        })
+        .filter(|triangle| {
+            let area = triangle.compute_signed_area();
+
+            area > 0.0 || (area < 0.0 && !cull_back_faces)
+        })
+        .collect()
+}
+
+
+// Fills the pixels of the tile covered by the triangle, the tile buffer only holds the tile pixels
+fn rasterize_triangle<S>(shader: &S, triangle: &ScreenTriangle<S::Varyings>, tile: &Tile, tile_buffer: &mut FrameBuffer)
+    where S: VertexShader + FragmentShader<<S as VertexShader>::Varyings> {
+
+    let (v0_screen, v1_screen, v2_screen) = (&triangle.positions[0], &triangle.positions[1], &triangle.positions[2]);
+    let varyings = [&triangle.varyings[0], &triangle.varyings[1], &triangle.varyings[2]];
+    let area = triangle.compute_signed_area();
+    let bounds = triangle.compute_bounds();
+
+    if bounds.x_min >= tile.x_max as f32 || bounds.x_max < tile.x_min as f32
+        || bounds.y_min >= tile.y_max as f32 || bounds.y_max < tile.y_min as f32 {
+        return;
+    }
+
+    let x0 = (tile.x_min as i32).max(bounds.x_min.floor() as i32) as usize;
+    let x1 = (tile.x_max as i32 - 1).min(bounds.x_max.floor() as i32) as usize;
+    let y0 = (tile.y_min as i32).max(bounds.y_min.floor() as i32) as usize;
+    let y1 = (tile.y_max as i32 - 1).min(bounds.y_max.floor() as i32) as usize;
+
+    for y in y0..(y1 + 1) {
+        for x in x0..(x1 + 1) {
+            let pixel_pos = Point::new(x as f32 + 0.5, y as f32 + 0.5, 0.0);
+
+            // Dividing by the signed area makes the coordinates positive inside back faces as well
+            let bar_coords = (
+                compute_edge_function(v1_screen, v2_screen, &pixel_pos) / area,
+                compute_edge_function(v2_screen, v0_screen, &pixel_pos) / area,
+                compute_edge_function(v0_screen, v1_screen, &pixel_pos) / area,
+            );
+
+            if bar_coords.0 < 0.0 || bar_coords.1 < 0.0 || bar_coords.2 < 0.0 {
+                continue;
+            }
+
+            let depth = 1.0 / (bar_coords.0 / v0_screen.z + bar_coords.1 / v1_screen.z + bar_coords.2 / v2_screen.z);
+            let pixel_idx = (y - tile.y_min) * tile_buffer.width + (x - tile.x_min);
+
+            if depth >= tile_buffer.depths[pixel_idx] {
+                continue;
+            }
+
+            // The screen space coordinates are weighted by the inverse depths for the perspective correct interpolation
+            let weights = (
+                bar_coords.0 / v0_screen.z * depth,
+                bar_coords.1 / v1_screen.z * depth,
+                bar_coords.2 / v2_screen.z * depth,
+            );
+            let fragment_varyings = S::Varyings::interpolate_barycentric(varyings, weights);
+
+            tile_buffer.depths[pixel_idx] = depth;
+            tile_buffer.colors[pixel_idx] = shader.shade_fragment(&fragment_varyings);
+        }
+    }
+}
+
+
+// The depth is kept as `z` for the perspective correct interpolation
+fn convert_to_screen(position: &ClipPosition, frame_width: usize, frame_height: usize) -> Point {
+    // The perspective division gives [-1, 1] coordinates
+    let x = position.x / position.w;
+    let y = position.y / position.w;
+
+    // To screen space, i.e [0, w] and [0, h]
+    Point::new((x + 1.0) * 0.5 * (frame_width as f32), (1.0 - y) * 0.5 * (frame_height as f32), position.w)
+}
+
+
+#[inline]
+fn compute_edge_function(u: &Point, v: &Point, point: &Point) -> f32 {
+    // Given two vectors u, v, computes the edge function for the given point
+    (point.x - u.x) * (v.y - u.y) - (point.y - u.y) * (v.x - u.x)
+}
+
+
+#[cfg(test)]
+mod pipeline_tests {
+    use super::*;
+
+    // Passes the camera space depth to the fragments as their color
+    struct DepthShader {
+        projection: Projection,
+    }
+
+    impl VertexShader for DepthShader {
+        type Varyings = f32;
+
+        fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<f32> {
+            ClipVertex {position: self.projection.project(&vertex.position), attributes: -vertex.position.z}
+        }
+    }
+
+    impl FragmentShader<f32> for DepthShader {
+        fn shade_fragment(&self, depth: &f32) -> Color {
+            Color::new(*depth, 0.0, 0.0)
+        }
+    }
+
+    fn create_shader(size: usize) -> DepthShader {
+        DepthShader {
+            projection: Projection {
+                viewing_plane: ViewingPlane::from_fov(std::f32::consts::PI * 0.5, size as u32, size as u32),
+                near_clipping_plane: 0.1,
+                far_clipping_plane: 100.0,
+            },
+        }
+    }
+
+    // A quad with the given depths at the bottom and at the top, which covers the whole frame
+    fn create_quad(bottom_depth: f32, top_depth: f32, counter_clockwise: bool) -> Mesh {
+        let mut mesh = Mesh::empty();
+        mesh.positions = vec![
+            -2.0 * bottom_depth, -bottom_depth, -bottom_depth,
+            2.0 * bottom_depth, -bottom_depth, -bottom_depth,
+            2.0 * top_depth, top_depth, -top_depth,
+            -2.0 * top_depth, top_depth, -top_depth,
+        ];
+        mesh.indices = if counter_clockwise { vec![0, 1, 2, 0, 2, 3] } else { vec![0, 2, 1, 0, 3, 2] };
+
+        mesh
+    }
+
+    #[test]
+    fn test_draw_mesh() {
+        let size = 100;
+        let shader = create_shader(size);
+        let mut frame = FrameBuffer::new(size, size, &Color::zero(), 100.0);
+
+        draw_mesh(&mut frame, &create_quad(1.0, 1.0, false), &shader, true);
+        assert!(frame.depths.iter().all(|depth| *depth == 100.0));
+
+        draw_mesh(&mut frame, &create_quad(1.0, 1.0, true), &shader, true);
+        assert!(frame.depths.iter().all(|depth| approx_eq!(f32, *depth, 1.0, epsilon = 0.0001)));
+
+        // Closer triangles are drawn over the current frame, even back faces if they are not culled
+        draw_mesh(&mut frame, &create_quad(0.5, 0.5, false), &shader, false);
+        assert!(frame.depths.iter().all(|depth| approx_eq!(f32, *depth, 0.5, epsilon = 0.0001)));
+        assert!(approx_eq!(f32, frame.colors[size * size / 2].r, 0.5, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn test_perspective_correct_interpolation() {
+        let size = 100;
+        let shader = create_shader(size);
+        let mut frame = FrameBuffer::new(size, size, &Color::zero(), 100.0);
+
+        draw_mesh(&mut frame, &create_quad(1.0, 4.0, true), &shader, true);
+
+        // The interpolated varying matches the depth of the fragment, which is not linear in the screen space.
+        // The middle row is where the plane is 1.6 units away, not at the average depth of 2.5
+        for (color, depth) in frame.colors.iter().zip(frame.depths.iter()) {
+            assert!(approx_eq!(f32, color.r, *depth, epsilon = 0.001));
+        }
+
+        let middle_depth = frame.depths[size * size / 2];
+        assert!(middle_depth > 1.5 && middle_depth < 1.7);
+    }
+}
@@ -1,3 +1,5 @@
+use std::f32::consts::PI;
+
 use rand::Rng;
 use rand::seq::SliceRandom;
 use rand::rngs::ThreadRng;
@@ -5,11 +7,17 @@ use rand::rngs::ThreadRng;
 use crate::ray_tracer::RenderOptions;
 use crate::camera::{Camera};
 use crate::surface::surface::{Surface, Hit, VisualData};
+use crate::surface::aabb::AxisAlignedBox;
+use crate::surface::bvh::{Bvh, BvhBuildOptions, build_bvh};
 use crate::basics::*;
+use crate::aov::{Aov, AovValues};
 
 
-static NUM_DIST_RT_SAMPLES: i32 = 5;
+pub static NUM_DIST_RT_SAMPLES: u32 = 5;
 static NUM_GLOSSY_REFL_RAYS: i32 = 10;
+static MAX_RAY_DEPTH: u32 = 5;
+static MAX_PATH_DEPTH: u32 = 16;
+static RUSSIAN_ROULETTE_DEPTH: u32 = 3;
 
 
 #[derive(Debug)]
@@ -20,47 +28,134 @@ pub struct Scene {
     pub lights: Vec<Light>,
     pub ambient_strength: f32,
     pub diffuse_strength: f32,
+    // Top-level hierarchy over the objects which have bounding boxes, the rest are tested one by one
+    bvh: Option<Bvh>,
+    bvh_objects: Vec<usize>,
+    unbounded_objects: Vec<usize>,
 }
 
 
 impl Scene {
+    pub fn new(
+        objects: Vec<Box<dyn Surface>>, camera: Camera, background_color: Color,
+        lights: Vec<Light>, ambient_strength: f32, diffuse_strength: f32) -> Scene {
+
+        let bboxes = objects.iter().map(|o| o.bounding_box()).collect::<Vec<Option<AxisAlignedBox>>>();
+        let bounded_objects = (0..objects.len()).filter(|idx| bboxes[*idx].is_some()).collect::<Vec<usize>>();
+        let unbounded_objects = (0..objects.len()).filter(|idx| bboxes[*idx].is_none()).collect::<Vec<usize>>();
+        let (bvh, bvh_objects) = if bounded_objects.is_empty() {
+            (None, vec![])
+        } else {
+            let bounded_bboxes = bounded_objects.iter().map(|idx| bboxes[*idx].clone().unwrap()).collect::<Vec<AxisAlignedBox>>();
+            let build_options = BvhBuildOptions {max_leaf_size: 2, ..BvhBuildOptions::defaults()};
+            let (bvh, order) = Bvh::from_build_node(&build_bvh(&bounded_bboxes, &build_options));
+
+            (Some(bvh), order.iter().map(|i| bounded_objects[*i]).collect())
+        };
+
+        Scene {
+            objects: objects,
+            camera: camera,
+            background_color: background_color,
+            lights: lights,
+            ambient_strength: ambient_strength,
+            diffuse_strength: diffuse_strength,
+            bvh: bvh,
+            bvh_objects: bvh_objects,
+            unbounded_objects: unbounded_objects,
+        }
+    }
+
+    // Bounds of all the finite objects, infinite ones (e.g. planes) are ignored
+    pub fn compute_bounds(&self) -> Option<AxisAlignedBox> {
+        let bounds = self.bvh_objects.iter()
+            .filter_map(|idx| self.objects[*idx].bounding_box())
+            .fold(AxisAlignedBox::empty(), |bounds, bbox| bounds.union(&bbox));
+
+        if bounds.is_empty() { None } else { Some(bounds) }
+    }
+
     pub fn get_object_idx_at_pixel(&self, i: u32, j: u32) -> Option<usize> {
         let ray = self.camera.generate_ray(i as f32, j as f32);
-        let mut closest_obj_idx = None;
-        let mut min_t = f32::INFINITY;
-
-        for (idx, object) in self.objects.iter().enumerate() {
-            if let Some(hit) = object.compute_hit(&ray, RayOptions::from_depth(0)) {
-                if hit.t < min_t {
-                    closest_obj_idx = Some(idx);
-                    min_t = hit.t;
+
+        self.compute_closest_object_hit(&ray, RayOptions::from_depth(0)).map(|(object_idx, _)| object_idx)
+    }
+
+    fn compute_closest_object_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<(usize, Hit)> {
+        let mut closest_hit: Option<(usize, Hit)> = None;
+
+        if ray_options.bv_type == BVType::None || self.bvh.is_none() {
+            // Testing all the objects is useful to compare the performance
+            for (idx, object) in self.objects.iter().enumerate() {
+                if let Some(hit) = object.compute_hit(ray, ray_options) {
+                    if closest_hit.as_ref().map_or(true, |(_, closest_hit)| hit.t < closest_hit.t) {
+                        closest_hit = Some((idx, hit));
+                    }
                 }
             }
-        }
 
-        closest_obj_idx
-    }
+            return closest_hit;
+        }
 
-    pub fn compute_ray_color(&self, ray_camera: &Ray, rng: &mut ThreadRng, ray_options: RayOptions) -> Color {
-        let mut hit = Hit::inf();
-        let mut vis = VisualData::zero();
+        // Bounding volumes of the top-level hierarchy are never displayed
+        let bvh_ray_options = RayOptions {bvh_display_level: i32::MAX, bv_type: BVType::BBox, ..ray_options};
+        closest_hit = self.bvh.as_ref().unwrap()
+            .compute_hit(ray, bvh_ray_options, |idx| self.objects[self.bvh_objects[idx]].compute_hit(ray, ray_options))
+            .and_then(|(idx, hit)| idx.map(|idx| (self.bvh_objects[idx], hit)));
 
-        for object in self.objects.iter() {
-            if let Some(another_hit) = object.compute_hit(ray_camera, ray_options) {
-                if another_hit.t < hit.t {
-                    hit = another_hit;
-                    vis = object.get_visual_data();
+        for idx in self.unbounded_objects.iter() {
+            if let Some(hit) = self.objects[*idx].compute_hit(ray, ray_options) {
+                if closest_hit.as_ref().map_or(true, |(_, closest_hit)| hit.t < closest_hit.t) {
+                    closest_hit = Some((*idx, hit));
                 }
             }
         }
 
-        if hit.t == f32::INFINITY {
-            return self.background_color.clone();
+        closest_hit
+    }
+
+    // Also returns the index of the hit object. The color of the visual data is already textured
+    fn compute_closest_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<(Hit, VisualData, usize)> {
+        self.compute_closest_object_hit(ray, ray_options)
+            .map(|(object_idx, hit)| {
+                let mut vis = self.objects[object_idx].get_hit_visual_data(&hit);
+                vis.color = vis.compute_color(&hit.uv);
+
+                (hit, vis, object_idx)
+            })
+    }
+
+    // Shadow rays only need to know if anything is closer than the light
+    fn is_occluded(&self, ray: &Ray, distance: f32, ray_options: RayOptions) -> bool {
+        if ray_options.bv_type == BVType::None || self.bvh.is_none() {
+            return self.objects.iter().any(|object| object.is_occluded(ray, ray_options, distance));
         }
 
+        let bvh_ray_options = RayOptions {bvh_display_level: i32::MAX, bv_type: BVType::BBox, ..ray_options};
+        let is_bounded_occluded = self.bvh.as_ref().unwrap().is_occluded(ray, bvh_ray_options, distance, |idx| {
+            self.objects[self.bvh_objects[idx]].is_occluded(ray, ray_options, distance)
+        });
+
+        is_bounded_occluded || self.unbounded_objects.iter().any(|idx| self.objects[*idx].is_occluded(ray, ray_options, distance))
+    }
+
+    // If `aovs` are given, the shading components of this ray's hit are recorded into them
+    pub fn compute_ray_color(
+        &self, ray_camera: &Ray, rng: &mut ThreadRng, ray_options: RayOptions,
+        mut aovs: Option<&mut AovValues>) -> Color {
+
+        let (hit, vis, object_idx) = match self.compute_closest_hit(ray_camera, ray_options) {
+            Some(closest_hit) => closest_hit,
+            None => return self.background_color.clone(),
+        };
+
         let mut color = &vis.color * self.ambient_strength;
         let hit_point_camera = ray_camera.compute_point(hit.t); // TODO: do not recompute the hit point
 
+        if let Some(aovs) = &mut aovs {
+            record_geometry_aovs(aovs, ray_camera, &hit, &vis, object_idx);
+        }
+
         for light_camera in self.lights.iter() {
             let light_location = if ray_options.light_shift.is_some() {
                 let (shift_right, shift_top) = ray_options.light_shift.unwrap();
@@ -75,28 +170,32 @@ impl Scene {
             let shadow_ray = Ray {
                 origin: &hit_point_camera + &(&light_dir.clone() * 0.0001),
                 direction: light_dir.clone(),
+                time: ray_camera.time,
             };
 
             // Diffuse component
-            let is_in_shadow = self.objects.iter()
-                // .filter(|o| !ptr::eq(*o, &*obj)) TODO: why did we need this?
-                .any(|o| o.compute_hit(&shadow_ray, ray_options)
-                .filter(|hit| hit.t < distance_to_light).is_some());
-
-            if !is_in_shadow {
+            if !self.is_occluded(&shadow_ray, distance_to_light, ray_options) {
                 let diffuse_cos = hit.normal.dot_product(&light_dir.normalize()).max(0.0);
                 let diffuse_light_color = &light_camera.color * (diffuse_cos * self.diffuse_strength);
                 color = &color + &diffuse_light_color;
+
+                if let Some(aovs) = &mut aovs {
+                    aovs.add_color(Aov::Diffuse, &diffuse_light_color);
+                }
             }
 
             // Specular light component
             if vis.specular_strength > 0.0 {
                 let eye_dir = (&self.camera.origin - &hit_point_camera).normalize();
                 let half_vector = (eye_dir + light_dir).normalize();
-                let spec_strength = vis.specular_strength * hit.normal.dot_product(&half_vector).max(0.0).powf(64.0);
+                let spec_strength = vis.specular_strength * hit.normal.dot_product(&half_vector).max(0.0).powf(vis.shininess);
                 let spec_color = &light_camera.color * spec_strength;
 
                 color = &color + &spec_color;
+
+                if let Some(aovs) = &mut aovs {
+                    aovs.add_color(Aov::Specular, &spec_color);
+                }
             }
 
             // Reflection component
@@ -106,20 +205,7 @@ impl Scene {
                 let reflection_rays;
 
                 if vis.reflection_glossiness > 0.0 {
-                    // Selecting the first orthogonal vector is a bit tricky
-                    // Since we need to make sure that it is not equal to zero
-                    // We just try different options: (0, -z, y), (-z, 0, x), (-y, x, 0)
-                    let mut u = Vec3::new(0.0, -reflection_dir.z, reflection_dir.y);
-                    if u.norm_squared() == 0.0 {
-                        u = Vec3::new(-reflection_dir.z, 0.0, reflection_dir.x);
-                    }
-                    if u.norm_squared() == 0.0 {
-                        u = Vec3::new(-reflection_dir.y, reflection_dir.x, 0.0);
-                    }
-                    u = u.normalize();
-
-                    // Selecting the second orthogonal vector is trivial
-                    let v = reflection_dir.cross_product(&u).normalize();
+                    let (u, v) = compute_orthonormal_basis(&reflection_dir);
 
                     // Now, we can generate the rays
                     reflection_rays = (0..NUM_GLOSSY_REFL_RAYS)
@@ -130,39 +216,241 @@ impl Scene {
                             Ray {
                                 origin: &hit_point_camera + &(&reflection_dir.clone() * 0.0001),
                                 direction: &reflection_dir + &u * u_weight + &v * v_weight,
+                                time: ray_camera.time,
                             }
                         }).collect::<Vec<Ray>>();
                 } else {
                     reflection_rays = vec![Ray {
                         origin: &hit_point_camera + &(&reflection_dir.clone() * 0.0001),
-                        direction: reflection_dir
+                        direction: reflection_dir,
+                        time: ray_camera.time,
                     }];
                 }
 
                 let reflection_color = reflection_rays.iter().map(
-                    |r| &self.compute_ray_color(r, rng, ray_options.increment_depth()) * (1.0 / reflection_rays.len() as f32))
+                    |r| &self.compute_ray_color(r, rng, ray_options.increment_depth(), None) * (1.0 / reflection_rays.len() as f32))
                     .fold(Color::zero(), |c1, c2| &c1 + &c2);
 
                 color = &color + &(&reflection_color * vis.reflection_strength);
+
+                if let Some(aovs) = &mut aovs {
+                    aovs.add_color(Aov::Reflection, &(&reflection_color * vis.reflection_strength));
+                }
+            }
+        }
+
+        if vis.transparency > 0.0 && ray_options.depth < MAX_RAY_DEPTH {
+            let transmission_color = self.compute_transmission_color(ray_camera, &hit, &hit_point_camera, &vis, rng, ray_options);
+
+            color = &(&color * (1.0 - vis.transparency)) + &(&transmission_color * vis.transparency);
+        }
+
+        if vis.transparency > 0.0 && ray_camera.direction.dot_product(&hit.normal) > 0.0 {
+            // The ray has been travelling inside the medium, so the light is attenuated
+            color = &color * &compute_attenuation(&vis.absorption, hit.t * ray_camera.direction.norm());
+        }
+
+        color
+    }
+
+    pub fn compute_path_color(
+        &self, ray_camera: &Ray, rng: &mut ThreadRng, ray_options: RayOptions,
+        mut aovs: Option<&mut AovValues>) -> Color {
+
+        // Path tracing integrator. As in `compute_ray_color`, `light.color` is the irradiance which
+        // a light delivers to a surface facing it, so next event estimation averages it over the light
+        // extent (`right` x `top`). Diffuse surfaces are Lambertian with the BRDF `albedo / pi`, both for
+        // the direct light and for the cosine-weighted bounces, where pi cancels out with the pdf.
+        // Only the geometry and albedo AOVs are recorded, lighting is not split into components
+        let mut radiance = Color::zero();
+        let mut throughput = Color {r: 1.0, g: 1.0, b: 1.0};
+        let mut ray = ray_camera.clone();
+
+        for depth in 0..MAX_PATH_DEPTH {
+            let (hit, vis, object_idx) = match self.compute_closest_hit(&ray, ray_options) {
+                Some(closest_hit) => closest_hit,
+                None => {
+                    // The background acts as an environment light
+                    radiance = &radiance + &(&throughput * &self.background_color);
+                    break;
+                }
+            };
+
+            if let Some(aovs) = aovs.take() {
+                record_geometry_aovs(aovs, &ray, &hit, &vis, object_idx);
+            }
+
+            let hit_point = ray.compute_point(hit.t);
+            let ray_dir = ray.direction.normalize();
+            let is_inside = ray_dir.dot_product(&hit.normal) > 0.0;
+
+            if vis.transparency > 0.0 && is_inside {
+                throughput = &throughput * &compute_attenuation(&vis.absorption, hit.t * ray.direction.norm());
+            }
+
+            // Selecting one of the lobes with the probability equal to its weight,
+            // so the weights cancel out and the throughput stays the same
+            let lobe_sample = rng.gen::<f32>();
+            let reflection_prob = (1.0 - vis.transparency) * vis.reflection_strength;
+            let next_dir;
+
+            if lobe_sample < vis.transparency {
+                let fresnel = compute_fresnel(&ray_dir, &hit.normal, vis.refractive_index);
+
+                next_dir = match fresnel.refraction_dir {
+                    Some(refraction_dir) if rng.gen::<f32>() >= fresnel.reflectance => refraction_dir,
+                    _ => fresnel.reflection_dir,
+                };
+            } else if lobe_sample < vis.transparency + reflection_prob {
+                let reflection_dir = &ray_dir + &(&hit.normal * (-2.0 * ray_dir.dot_product(&hit.normal)));
+                let (u, v) = compute_orthonormal_basis(&reflection_dir);
+                let u_weight = vis.reflection_glossiness * (rng.gen::<f32>() - 0.5);
+                let v_weight = vis.reflection_glossiness * (rng.gen::<f32>() - 0.5);
+
+                next_dir = (&reflection_dir + &u * u_weight + &v * v_weight).normalize();
+            } else {
+                let normal = if is_inside { -&hit.normal } else { hit.normal.clone() };
+                let direct_light = self.compute_direct_light(&ray, &hit_point, &normal, &vis, rng, ray_options);
+
+                radiance = &radiance + &(&throughput * &direct_light);
+                throughput = &throughput * &vis.color;
+                next_dir = sample_cosine_weighted_hemisphere(&normal, rng);
+            }
+
+            if depth >= RUSSIAN_ROULETTE_DEPTH {
+                let survival_prob = throughput.r.max(throughput.g).max(throughput.b).min(0.95);
+
+                if rng.gen::<f32>() >= survival_prob {
+                    break;
+                }
+
+                throughput = &throughput * (1.0 / survival_prob);
+            }
+
+            ray = Ray {
+                origin: &hit_point + &(&next_dir * 0.0001),
+                direction: next_dir,
+                time: ray.time,
+            };
+        }
+
+        radiance
+    }
+
+    // The highlights are seen from the origin of `ray`, i.e. from the previous vertex of the path
+    fn compute_direct_light(
+        &self, ray: &Ray, hit_point: &Point, normal: &Vec3, vis: &VisualData,
+        rng: &mut ThreadRng, ray_options: RayOptions) -> Color {
+
+        let view_dir = -&ray.direction.normalize();
+        let mut color = Color::zero();
+
+        for light in self.lights.iter() {
+            let light_location = &light.location + &(&light.right * rng.gen::<f32>() + &light.top * rng.gen::<f32>());
+            let distance_to_light = (&light_location - hit_point).norm();
+            let light_dir = (&light_location - hit_point).normalize();
+            let diffuse_cos = normal.dot_product(&light_dir);
+
+            if diffuse_cos <= 0.0 {
+                continue;
             }
 
-            color = (&color).clamp();
+            let shadow_ray = Ray {
+                origin: hit_point + &(&light_dir * 0.0001),
+                direction: light_dir.clone(),
+                time: ray.time,
+            };
+
+            if self.is_occluded(&shadow_ray, distance_to_light, ray_options) {
+                continue;
+            }
+
+            let diffuse_color = &(&vis.color * &light.color) * (diffuse_cos * self.diffuse_strength / PI);
+            color = &color + &diffuse_color;
+
+            if vis.specular_strength > 0.0 {
+                let half_vector = (&view_dir + &light_dir).normalize();
+                let spec_strength = vis.specular_strength * normal.dot_product(&half_vector).max(0.0).powf(vis.shininess);
+
+                color = &color + &(&light.color * spec_strength);
+            }
         }
 
         color
     }
 
+    fn compute_transmission_color(
+        &self, ray: &Ray, hit: &Hit, hit_point: &Point, vis: &VisualData,
+        rng: &mut ThreadRng, ray_options: RayOptions) -> Color {
+
+        let fresnel = compute_fresnel(&ray.direction.normalize(), &hit.normal, vis.refractive_index);
+        let reflection_ray = Ray {
+            origin: hit_point + &(&fresnel.reflection_dir * 0.0001),
+            direction: fresnel.reflection_dir.clone(),
+            time: ray.time,
+        };
+        let reflection_color = self.compute_ray_color(&reflection_ray, rng, ray_options.increment_depth(), None);
+
+        match fresnel.refraction_dir {
+            // Total internal reflection
+            None => reflection_color,
+            Some(refraction_dir) => {
+                let refraction_ray = Ray {
+                    origin: hit_point + &(&refraction_dir * 0.0001),
+                    direction: refraction_dir,
+                    time: ray.time,
+                };
+                let refraction_color = self.compute_ray_color(&refraction_ray, rng, ray_options.increment_depth(), None);
+
+                &(&reflection_color * fresnel.reflectance) + &(&refraction_color * (1.0 - fresnel.reflectance))
+            }
+        }
+    }
+
     pub fn compute_pixel(&self, i: u32, j: u32, render_options: &RenderOptions) -> Color {
+        self.compute_pixel_with_aovs(i, j, render_options, None)
+    }
+
+    // Computes the pixel color and, if `aovs` are given, fills them in the same pass
+    pub fn compute_pixel_with_aovs(
+        &self, i: u32, j: u32, render_options: &RenderOptions,
+        mut aovs: Option<&mut AovValues>) -> Color {
+
         // let shifts = (0..25).map(|_| rng.gen::<f32>()).collect::<Vec<f32>>();
         let rays;
         let mut rng = rand::thread_rng();
 
-        if render_options.use_supersampling || render_options.use_soft_shadows {
-            rays = iproduct!(0..NUM_DIST_RT_SAMPLES, 0..NUM_DIST_RT_SAMPLES)
-                .map(|p: (i32, i32)| self.camera.generate_ray(
-                    (i as f32) + (p.0 as f32) / NUM_DIST_RT_SAMPLES as f32 + rng.gen::<f32>(),
-                    (j as f32) + (p.1 as f32) / NUM_DIST_RT_SAMPLES as f32 + rng.gen::<f32>()
+        let num_samples = render_options.num_dist_rt_samples;
+
+        if render_options.use_supersampling || render_options.use_soft_shadows || render_options.use_motion_blur
+            || self.camera.has_lens() {
+            // The aperture is stratified separately and shuffled, so the lens and the pixel samples are not correlated
+            let mut lens_samples = iproduct!(0..num_samples, 0..num_samples)
+                .map(|p: (u32, u32)| (
+                    (p.0 as f32 + rng.gen::<f32>()) / num_samples as f32,
+                    (p.1 as f32 + rng.gen::<f32>()) / num_samples as f32,
                 ))
+                .collect::<Vec<(f32, f32)>>();
+            lens_samples.shuffle(&mut rng);
+
+            // The shutter interval is stratified the same way, each ray sees the moving objects at a different time
+            let num_rays = num_samples * num_samples;
+            let mut times = (0..num_rays)
+                .map(|k| if render_options.use_motion_blur { (k as f32 + rng.gen::<f32>()) / num_rays as f32 } else { 0.0 })
+                .collect::<Vec<f32>>();
+            times.shuffle(&mut rng);
+
+            rays = iproduct!(0..num_samples, 0..num_samples)
+                .zip(lens_samples)
+                .zip(times)
+                .map(|((p, lens_sample), time): (((u32, u32), (f32, f32)), f32)| Ray {
+                    time: time,
+                    ..self.camera.generate_lens_ray(
+                        (i as f32) + (p.0 as f32) / num_samples as f32 + rng.gen::<f32>(),
+                        (j as f32) + (p.1 as f32) / num_samples as f32 + rng.gen::<f32>(),
+                        lens_sample,
+                    )
+                })
                 .collect::<Vec<Ray>>();
         } else {
             rays = vec![self.camera.generate_ray(i as f32 + 0.5, j as f32 + 0.5)]
@@ -171,44 +459,150 @@ impl Scene {
         let mut light_shifts;
 
         if render_options.use_soft_shadows {
-            light_shifts = iproduct!(0..NUM_DIST_RT_SAMPLES, 0..NUM_DIST_RT_SAMPLES)
-                .map(|p: (i32, i32)| Some((
-                    (p.0 as f32) / NUM_DIST_RT_SAMPLES as f32 + rng.gen::<f32>(),
-                    (p.1 as f32) / NUM_DIST_RT_SAMPLES as f32 + rng.gen::<f32>()
+            light_shifts = iproduct!(0..num_samples, 0..num_samples)
+                .map(|p: (u32, u32)| Some((
+                    (p.0 as f32) / num_samples as f32 + rng.gen::<f32>(),
+                    (p.1 as f32) / num_samples as f32 + rng.gen::<f32>()
                 )))
                 .collect::<Vec<Option<(f32, f32)>>>();
             light_shifts.shuffle(&mut rng);
         } else {
-            light_shifts = vec![None; 25];
+            light_shifts = vec![None; rays.len()];
         };
 
         rays
             .iter()
             .enumerate()
-            .map(|(i, ray)| &self.compute_ray_color(ray, &mut rng, RayOptions {
+            .map(|(i, ray)| {
+                let ray_options = RayOptions {
                     depth: 0,
                     light_shift: light_shifts[i],
                     mesh_normal_type: render_options.ray_opts.mesh_normal_type,
                     bvh_display_level: render_options.ray_opts.bvh_display_level,
                     bv_type: render_options.ray_opts.bv_type,
-                }) * (1.0 / rays.len() as f32))
+                };
+                let mut sample_aovs = aovs.as_ref().map(|_| AovValues::new());
+                let color = match render_options.integrator {
+                    Integrator::Whitted => self.compute_ray_color(ray, &mut rng, ray_options, sample_aovs.as_mut()),
+                    Integrator::PathTracing => self.compute_path_color(ray, &mut rng, ray_options, sample_aovs.as_mut()),
+                };
+
+                if let (Some(aovs), Some(sample_aovs)) = (aovs.as_mut(), &sample_aovs) {
+                    aovs.add_sample(sample_aovs, 1.0 / rays.len() as f32, i == 0);
+                }
+
+                &color * (1.0 / rays.len() as f32)
+            })
             .fold(Color::zero(), |c1, c2| &c1 + &c2)
     }
 }
 
 
+fn record_geometry_aovs(aovs: &mut AovValues, ray: &Ray, hit: &Hit, vis: &VisualData, object_idx: usize) {
+    let normal = hit.normal.normalize();
+
+    aovs.set(Aov::Depth, hit.t * ray.direction.norm());
+    aovs.set_color(Aov::Normal, &Color::new(normal.x, normal.y, normal.z));
+    aovs.set(Aov::ObjectId, object_idx as f32);
+    aovs.set_color(Aov::Albedo, &vis.color);
+}
+
+
+struct Fresnel {
+    reflection_dir: Vec3,
+    refraction_dir: Option<Vec3>,
+    reflectance: f32,
+}
+
+
+fn compute_fresnel(ray_dir: &Vec3, hit_normal: &Vec3, refractive_index: f32) -> Fresnel {
+    // Assumes that `ray_dir` is normalized. Refraction direction is None for total internal reflection
+    let is_entering = ray_dir.dot_product(hit_normal) < 0.0;
+
+    // The normal should look against the ray and eta is the ratio of refractive indices n1 / n2
+    let (normal, eta) = if is_entering {
+        (hit_normal.clone(), 1.0 / refractive_index)
+    } else {
+        (-hit_normal, refractive_index)
+    };
+    let cos_incident = -ray_dir.dot_product(&normal);
+    let reflection_dir = ray_dir + &(&normal * (2.0 * cos_incident));
+    let sin_transmitted_squared = eta * eta * (1.0 - cos_incident * cos_incident);
+
+    if sin_transmitted_squared > 1.0 {
+        return Fresnel {reflection_dir: reflection_dir, refraction_dir: None, reflectance: 1.0};
+    }
+
+    let cos_transmitted = (1.0 - sin_transmitted_squared).sqrt();
+    let refraction_dir = (&(ray_dir * eta) + &(&normal * (eta * cos_incident - cos_transmitted))).normalize();
+
+    // Schlick's approximation of the Fresnel term, which uses the angle in the less dense medium
+    let r0 = ((1.0 - eta) / (1.0 + eta)).powi(2);
+    let cos_theta = if eta > 1.0 { cos_transmitted } else { cos_incident };
+
+    Fresnel {
+        reflection_dir: reflection_dir,
+        refraction_dir: Some(refraction_dir),
+        reflectance: r0 + (1.0 - r0) * (1.0 - cos_theta).powi(5),
+    }
+}
+
+
+fn compute_attenuation(absorption: &Color, distance: f32) -> Color {
+    // Beer-Lambert law
+    Color {
+        r: (-absorption.r * distance).exp(),
+        g: (-absorption.g * distance).exp(),
+        b: (-absorption.b * distance).exp(),
+    }
+}
+
+
+fn compute_orthonormal_basis(dir: &Vec3) -> (Vec3, Vec3) {
+    // Selecting the first orthogonal vector is a bit tricky
+    // Since we need to make sure that it is not equal to zero
+    // We just try different options: (0, -z, y), (-z, 0, x), (-y, x, 0)
+    let mut u = Vec3::new(0.0, -dir.z, dir.y);
+    if u.norm_squared() == 0.0 {
+        u = Vec3::new(-dir.z, 0.0, dir.x);
+    }
+    if u.norm_squared() == 0.0 {
+        u = Vec3::new(-dir.y, dir.x, 0.0);
+    }
+    u = u.normalize();
+
+    // Selecting the second orthogonal vector is trivial
+    let v = dir.cross_product(&u).normalize();
+
+    (u, v)
+}
+
+
+fn sample_cosine_weighted_hemisphere(normal: &Vec3, rng: &mut ThreadRng) -> Vec3 {
+    // Malley's method: sampling a unit disk uniformly and projecting it onto the hemisphere
+    let (u, v) = compute_orthonormal_basis(normal);
+    let r_squared = rng.gen::<f32>();
+    let r = r_squared.sqrt();
+    let phi = 2.0 * PI * rng.gen::<f32>();
+
+    (&(&u * (r * phi.cos()) + &v * (r * phi.sin())) + &(normal * (1.0 - r_squared).sqrt())).normalize()
+}
+
+
 #[cfg(test)]
 mod scene_tests {
     use super::*;
-    use crate::surface::quadrics::Sphere;
+    use crate::surface::quadrics::{Sphere, Plane};
+    use crate::surface::surface::TransformedSurface;
+    use crate::matrix::{AffineMat3, AnimatedTransform};
+    use crate::camera::ProjectionType;
 
     #[test]
     fn test_sphere() {
         let sphere = Sphere {
             center: Point {x: 0.0, y: 0.0, z: 0.0},
             radius: 1.0,
-            color: Color {r: 1.0, g: 0.0, b: 0.0},
-            specular_strength: 0.0
+            vis: VisualData::from_color(&Color {r: 1.0, g: 0.0, b: 0.0}),
         };
         let point_a = Point {x: 0.0, y: 1.0, z: 0.0};
         let point_b = Point {x: 0.0, y: 0.0, z: -1.0};
@@ -225,14 +619,175 @@ mod scene_tests {
 
         let ray_a = Ray {
             origin: Point {x: 0.0, y: 0.0, z: -5.0},
-            direction: Vec3 { x: 0.0, y: 0.0, z: 1.0 }
+            direction: Vec3 { x: 0.0, y: 0.0, z: 1.0 },
+            time: 0.0,
         };
         let ray_b = Ray {
             origin: Point {x: 0.0, y: 0.0, z: -(2.0_f32.sqrt())},
-            direction: Vec3 { x: 0.0, y: 1.0 / 2.0_f32.sqrt(), z: 1.0 / 2.0_f32.sqrt() }
+            direction: Vec3 { x: 0.0, y: 1.0 / 2.0_f32.sqrt(), z: 1.0 / 2.0_f32.sqrt() },
             // direction: (&Vec3 { x: 0.0, y: 1.0, z: 1.0 }).normalize()
+            time: 0.0,
         };
         assert_eq!(sphere.compute_hit(&ray_a, RayOptions::from_depth(0)).unwrap().t, 4.0);
         assert!(approx_eq!(f32, sphere.compute_hit(&ray_b, RayOptions::from_depth(0)).unwrap().t, 1.0, epsilon = 0.001));
     }
+
+    #[test]
+    fn test_top_level_bvh() {
+        let mut objects: Vec<Box<dyn Surface>> = vec![Box::new(Plane::from_y(-2.0, Color::zero()))];
+        for (i, j) in iproduct!(0..10, 0..10) {
+            objects.push(Box::new(Sphere::from_position(0.4, Point::new(i as f32 - 5.0, j as f32 - 5.0, 10.0))));
+        }
+
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let scene = Scene::new(objects, camera, Color::zero(), vec![], 0.0, 0.0);
+        let linear_options = RayOptions {bv_type: BVType::None, ..RayOptions::from_depth(0)};
+
+        assert_eq!(scene.unbounded_objects, vec![0]);
+
+        for (i, j) in iproduct!(0..64, 0..48) {
+            let ray = scene.camera.generate_ray(i as f32, j as f32);
+            let hit = scene.compute_closest_object_hit(&ray, RayOptions::from_depth(0));
+            let linear_hit = scene.compute_closest_object_hit(&ray, linear_options);
+
+            assert_eq!(hit.map(|h| h.0), linear_hit.map(|h| h.0));
+        }
+    }
+
+    #[test]
+    fn test_direct_light_highlight() {
+        let light = Light {
+            location: Point::new(0.0, 5.0, 0.0),
+            color: Color {r: 1.0, g: 1.0, b: 1.0},
+            right: Vec3::new(0.0, 0.0, 0.0),
+            top: Vec3::new(0.0, 0.0, 0.0),
+        };
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let scene = Scene::new(vec![], camera, Color::zero(), vec![light], 0.0, 0.0);
+        let vis = VisualData {specular_strength: 1.0, ..VisualData::from_color(&Color::zero())};
+        let (hit_point, normal) = (Point::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        let mut rng = rand::thread_rng();
+
+        // The highlight is seen from the previous path vertex, which is straight above, and not from the camera
+        let ray_above = Ray {origin: Point::new(0.0, 1.0, 0.0), direction: Vec3::new(0.0, -1.0, 0.0), time: 0.0};
+        let ray_side = Ray {origin: Point::new(1.0, 0.0, 0.0), direction: Vec3::new(-1.0, 0.0, 0.0), time: 0.0};
+        let from_above = scene.compute_direct_light(&ray_above, &hit_point, &normal, &vis, &mut rng, RayOptions::from_depth(1));
+        let from_side = scene.compute_direct_light(&ray_side, &hit_point, &normal, &vis, &mut rng, RayOptions::from_depth(1));
+
+        assert!(approx_eq!(f32, from_above.r, 1.0, epsilon = 0.0001));
+        assert!(from_side.r < 0.001);
+    }
+
+    #[test]
+    fn test_direct_light_normalization() {
+        let light = Light {
+            location: Point::new(0.0, 5.0, 0.0),
+            color: Color {r: 1.0, g: 1.0, b: 1.0},
+            right: Vec3::new(0.0, 0.0, 0.0),
+            top: Vec3::new(0.0, 0.0, 0.0),
+        };
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let scene = Scene::new(vec![], camera, Color::zero(), vec![light], 0.0, 1.0);
+        let vis = VisualData::from_color(&Color {r: 1.0, g: 1.0, b: 1.0});
+        let normal = Vec3::new(0.0, 1.0, 0.0);
+        let mut rng = rand::thread_rng();
+        let ray = Ray {origin: Point::new(0.0, 1.0, 0.0), direction: Vec3::new(0.0, -1.0, 0.0), time: 0.0};
+        let direct_light = scene.compute_direct_light(
+            &ray, &Point::new(0.0, 0.0, 0.0), &normal, &vis, &mut rng, RayOptions::from_depth(1));
+
+        // A white Lambertian surface reflects 1 / pi of the irradiance, the same as the bounces assume
+        assert!(approx_eq!(f32, direct_light.r, 1.0 / PI, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn test_shadow_ray_occlusion() {
+        // The scaled sphere spans z from 8 to 12, so the object space distances differ from the world ones
+        let sphere = TransformedSurface::new(
+            &AffineMat3::translation(Vec3::new(0.0, 0.0, 10.0)) * &AffineMat3::scale(Vec3::new(2.0, 2.0, 2.0)),
+            Sphere::new(VisualData::grey()),
+        );
+        let objects: Vec<Box<dyn Surface>> = vec![Box::new(Plane::from_y(-2.0, Color::zero())), Box::new(sphere)];
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let scene = Scene::new(objects, camera, Color::zero(), vec![], 0.0, 0.0);
+        let ray = Ray {
+            origin: Point::new(0.0, 0.0, 0.0),
+            direction: Vec3::new(0.0, 0.0, 1.0),
+            time: 0.0,
+        };
+
+        for ray_options in &[RayOptions::from_depth(0), RayOptions {bv_type: BVType::None, ..RayOptions::from_depth(0)}] {
+            assert!(!scene.is_occluded(&ray, 7.9, *ray_options));
+            assert!(scene.is_occluded(&ray, 8.1, *ray_options));
+        }
+
+        // Only the plane is below
+        let ray_down = Ray {
+            origin: Point::new(0.0, 0.0, 0.0),
+            direction: Vec3::new(0.0, -1.0, 0.0),
+            time: 0.0,
+        };
+        assert!(!scene.is_occluded(&ray_down, 1.9, RayOptions::from_depth(0)));
+        assert!(scene.is_occluded(&ray_down, 2.1, RayOptions::from_depth(0)));
+    }
+
+    #[test]
+    fn test_motion_hit() {
+        // The sphere moves from x = -3 to x = 3 while the shutter is open
+        let sphere = TransformedSurface::new(
+            AffineMat3::translation(Vec3::new(-3.0, 0.0, 10.0)),
+            Sphere::new(VisualData::grey()),
+        ).with_end_transformation(AffineMat3::translation(Vec3::new(3.0, 0.0, 10.0)));
+        let objects: Vec<Box<dyn Surface>> = vec![Box::new(sphere)];
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let scene = Scene::new(objects, camera, Color::zero(), vec![], 0.0, 0.0);
+        let ray_at = |time: f32| Ray {
+            origin: Point::new(0.0, 0.0, 0.0),
+            direction: Vec3::new(0.0, 0.0, 1.0),
+            time: time,
+        };
+
+        assert!(scene.compute_closest_object_hit(&ray_at(0.0), RayOptions::from_depth(0)).is_none());
+        assert!(scene.compute_closest_object_hit(&ray_at(1.0), RayOptions::from_depth(0)).is_none());
+
+        let hit = scene.compute_closest_object_hit(&ray_at(0.5), RayOptions::from_depth(0)).unwrap();
+        assert!(approx_eq!(f32, hit.1.t, 9.0, epsilon = 0.0001));
+        assert!(scene.is_occluded(&ray_at(0.5), 9.1, RayOptions::from_depth(0)));
+        assert!(!scene.is_occluded(&ray_at(0.1), 20.0, RayOptions::from_depth(0)));
+    }
+
+    #[test]
+    fn test_motion_bounds() {
+        // The sphere is away from the origin, so it swings around on an arc while rotating
+        let sphere = Sphere::from_position(0.5, Point::new(3.0, 0.0, 0.0));
+        let sphere_bbox = sphere.bounding_box().unwrap();
+        let end_transformation = AffineMat3::rotation(170.0_f32.to_radians(), &Vec3::new(0.0, 1.0, 0.0));
+        let motion = AnimatedTransform::new(AffineMat3::identity(), end_transformation.clone());
+        let bounds = TransformedSurface::new(AffineMat3::identity(), sphere)
+            .with_end_transformation(end_transformation)
+            .bounding_box().unwrap();
+
+        for step in 0..=1000 {
+            let bbox = sphere_bbox.transform(&motion.interpolate(step as f32 / 1000.0));
+
+            assert!(bbox.min_corner.x >= bounds.min_corner.x && bbox.max_corner.x <= bounds.max_corner.x);
+            assert!(bbox.min_corner.z >= bounds.min_corner.z && bbox.max_corner.z <= bounds.max_corner.z);
+        }
+    }
+
+    #[test]
+    fn test_scene_bounds() {
+        let objects: Vec<Box<dyn Surface>> = vec![
+            Box::new(Plane::from_y(-2.0, Color::zero())),
+            Box::new(Sphere::from_position(1.0, Point::new(-2.0, 0.0, 5.0))),
+            Box::new(Sphere::from_position(0.5, Point::new(3.0, 1.0, 8.0))),
+        ];
+        let camera = Camera::from_z_position(-1.0, PI / 3.0, ProjectionType::Perspective, 64, 48);
+        let bounds = Scene::new(objects, camera, Color::zero(), vec![], 0.0, 0.0).compute_bounds().unwrap();
+
+        // The plane is infinite, so it is not included
+        assert!(approx_eq!(f32, bounds.min_corner.x, -3.0));
+        assert!(approx_eq!(f32, bounds.min_corner.y, -1.0));
+        assert!(approx_eq!(f32, bounds.max_corner.x, 3.5));
+        assert!(approx_eq!(f32, bounds.max_corner.z, 8.5));
+    }
 }
@@ -0,0 +1,740 @@
+// Text scene description format.
+//
+// A scene file consists of statements, one per line. Each statement starts with a keyword
+// followed by `key=value` fields. Vectors and colors are written as comma-separated numbers
+// without spaces (e.g. `color=1,0.5,0`), angles are specified in degrees and everything
+// after `#` is a comment. Example:
+//
+//     camera position=0,0,-7 yaw=-90 pitch=0 fov=90 projection=perspective
+//     background color=0.204,0.596,0.86
+//     lighting ambient=0.7 diffuse=0.5
+//     light location=-0.1,10,-0.1 color=1,1,1 right=0.2,0,0 top=0,0,0.2
+//     plane point=0,-1.4,0 normal=0,1,0 color=0.5,0.5,0.5
+//     sphere color=1,0,0 specular=0.5 reflection=0.5 translate=1,0,0 scale=0.5
+//     mesh path=../teapot.obj scale=0.1 rotate=-90,0,1,0
+//
+// Objects are `sphere`, `plane`, `ellipsoid`, `cone` and `mesh`. Each of them accepts
+// material fields (`color`, `specular`, `shininess`, `reflection`, `glossiness`, `ior`,
+// `transparency`, `absorption`, `texture`, `wrap=repeat|mirror|clamp`) and transformation
+// fields (`scale`, `rotate=angle,x,y,z`, `translate`), which are applied in this exact order.
+// With motion blur, objects move to `end_scale`, `end_rotate` and `end_translate` while the
+// shutter is open. The end fields which are not given are the same as the start ones and
+// rotations follow the shortest arc between the start and the end orientation.
+// Meshes without material fields use the materials of their .mtl files.
+// Material and background colors are given in sRGB, as in image editors, and are converted to
+// the linear space for rendering, while light colors are linear intensities.
+// Instead of `yaw` and `pitch`, the camera can be turned towards a point with `target=x,y,z`,
+// `roll` rotates it around the viewing direction. Depth of field is enabled by the `aperture`
+// radius together with the `focus` distance, `blades=n` makes the aperture polygonal.
+// Mesh and texture paths are resolved relative to the scene file.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::ray_tracer::RenderOptions;
+use crate::scene::Scene;
+use crate::camera::{CameraOptions, ProjectionType, ThinLens, ApertureShape};
+use crate::surface::surface::{Surface, TransformedSurface, VisualData};
+use crate::surface::quadrics::{Sphere, Plane, Ellipsoid, Cone};
+use crate::surface::mesh::TriangleMesh;
+use crate::texture::{Texture, WrapMode};
+use crate::error::RtrsError;
+use crate::matrix::{AffineMat3, DiagMat3};
+use crate::basics::*;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneParseError {
+    pub line: usize,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+
+impl SceneParseError {
+    fn new(line: usize, field: Option<&str>, message: String) -> Self {
+        SceneParseError {
+            line: line,
+            field: field.map(|f| f.to_string()),
+            message: message,
+        }
+    }
+}
+
+
+impl fmt::Display for SceneParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "line {}, field `{}`: {}", self.line, field, self.message),
+            None => write!(f, "line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+
+impl Error for SceneParseError {}
+
+
+#[derive(Debug, Clone)]
+pub enum Shape {
+    Sphere {center: Point, radius: f32},
+    Plane {point: Point, normal: Vec3},
+    Ellipsoid {center: Point, radii: Vec3},
+    Cone {apex: Point, height: f32, half_angle: f32},
+    Mesh {path: String, mesh_idx: usize, use_materials: bool},
+}
+
+
+#[derive(Debug, Clone)]
+pub struct ObjectDescription {
+    pub shape: Shape,
+    pub vis: VisualData,
+    pub transformation: AffineMat3,
+    pub end_transformation: Option<AffineMat3>, // At the shutter closing, the object is static if not given
+    pub texture_idx: Option<usize>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SceneDescription {
+    pub camera_opts: CameraOptions,
+    pub fov: f32,
+    pub projection_type: ProjectionType,
+    pub lens: Option<ThinLens>,
+    pub background_color: Color,
+    pub ambient_strength: f32,
+    pub diffuse_strength: f32,
+    pub lights: Vec<Light>,
+    pub objects: Vec<ObjectDescription>,
+    pub mesh_paths: Vec<String>,
+    pub meshes: Vec<TriangleMesh>,
+    // The same image can be used with different wrap modes
+    pub texture_paths: Vec<(String, WrapMode)>,
+    pub textures: Vec<Arc<Texture>>,
+}
+
+
+impl SceneDescription {
+    pub fn from_file(path: &str) -> Result<SceneDescription, RtrsError> {
+        let mut description = SceneDescription::parse_file(path)?;
+        let path_buf = PathBuf::from(path);
+
+        description.load_meshes(path_buf.parent().unwrap_or(Path::new("")))?;
+        description.load_textures(path_buf.parent().unwrap_or(Path::new("")))?;
+
+        Ok(description)
+    }
+
+    // Neither the meshes nor the textures are loaded, their paths are relative to the scene file
+    pub fn parse_file(path: &str) -> Result<SceneDescription, RtrsError> {
+        let path_buf = PathBuf::from(path);
+        let source = fs::read_to_string(&path_buf).map_err(|err| RtrsError::from_io(&path_buf, err))?;
+
+        SceneDescription::parse(&source).map_err(|err| RtrsError::SceneParse(path_buf.clone(), err))
+    }
+
+    pub fn parse(source: &str) -> Result<SceneDescription, SceneParseError> {
+        let mut description = SceneDescription::empty();
+
+        for (line_idx, line) in source.lines().enumerate() {
+            let line_num = line_idx + 1;
+            let line = line.split('#').next().unwrap_or("").trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let keyword = tokens.next().unwrap();
+            let mut fields = Fields::parse(line_num, tokens)?;
+
+            match keyword {
+                "camera" => {
+                    if let Some(position) = fields.take_vec3("position")? {
+                        description.camera_opts.position = position;
+                    }
+                    if let Some(yaw) = fields.take_f32("yaw")? {
+                        description.camera_opts.yaw = yaw.to_radians();
+                    }
+                    if let Some(pitch) = fields.take_f32("pitch")? {
+                        description.camera_opts.pitch = pitch.to_radians();
+                    }
+                    if let Some(target) = fields.take_vec3("target")? {
+                        description.camera_opts.look_at(&target);
+                    }
+                    if let Some(roll) = fields.take_f32("roll")? {
+                        description.camera_opts.roll = roll.to_radians();
+                    }
+                    if let Some(fov) = fields.take_f32("fov")? {
+                        description.fov = fov.to_radians();
+                    }
+                    if let Some(projection) = fields.take_str("projection") {
+                        description.projection_type = match projection.as_str() {
+                            "perspective" => ProjectionType::Perspective,
+                            "parallel" => ProjectionType::Parallel,
+                            _ => return Err(fields.error("projection", format!("unknown projection `{}`", projection))),
+                        };
+                    }
+                    if let Some(lens) = fields.take_lens()? {
+                        description.lens = Some(lens);
+                    }
+                },
+                "background" => {
+                    description.background_color = fields.require_color("color")?;
+                },
+                "lighting" => {
+                    if let Some(ambient) = fields.take_f32("ambient")? {
+                        description.ambient_strength = ambient;
+                    }
+                    if let Some(diffuse) = fields.take_f32("diffuse")? {
+                        description.diffuse_strength = diffuse;
+                    }
+                },
+                "light" => {
+                    description.lights.push(Light {
+                        location: fields.require_vec3("location")?.into(),
+                        // Light colors are linear intensities, which can be larger than 1
+                        color: fields.take_vec3("color")?.map_or(Color::new(1.0, 1.0, 1.0), |v| Color::new(v.x, v.y, v.z)),
+                        right: fields.take_vec3("right")?.unwrap_or(Vec3::zero()),
+                        top: fields.take_vec3("top")?.unwrap_or(Vec3::zero()),
+                    });
+                },
+                "sphere" | "plane" | "ellipsoid" | "cone" | "mesh" => {
+                    let shape = match keyword {
+                        "sphere" => Shape::Sphere {
+                            center: fields.take_vec3("center")?.map_or(Point::zero(), |v| v.into()),
+                            radius: fields.take_f32("radius")?.unwrap_or(1.0),
+                        },
+                        "plane" => {
+                            let point = fields.take_vec3("point")?.map_or(Point::zero(), |v| v.into());
+                            let normal = fields.take_vec3("normal")?.unwrap_or(Vec3::new(0.0, 1.0, 0.0));
+
+                            if normal.norm() == 0.0 {
+                                return Err(fields.error("normal", "the normal must not be zero".to_string()));
+                            }
+
+                            Shape::Plane {point: point, normal: normal.normalize()}
+                        },
+                        "ellipsoid" => Shape::Ellipsoid {
+                            center: fields.take_vec3("center")?.map_or(Point::zero(), |v| v.into()),
+                            radii: fields.require_vec3("radii")?,
+                        },
+                        "cone" => Shape::Cone {
+                            apex: fields.take_vec3("apex")?.map_or(Point::zero(), |v| v.into()),
+                            height: fields.take_f32("height")?.unwrap_or(1.0),
+                            half_angle: fields.take_f32("half_angle")?.unwrap_or(30.0).to_radians(),
+                        },
+                        _ => {
+                            let path = fields.take_str("path")
+                                .ok_or(fields.error("path", "a path to an .obj file is required".to_string()))?;
+                            let mesh_idx = match description.mesh_paths.iter().position(|p| p == &path) {
+                                Some(idx) => idx,
+                                None => {
+                                    description.mesh_paths.push(path.clone());
+                                    description.mesh_paths.len() - 1
+                                }
+                            };
+
+                            Shape::Mesh {path: path, mesh_idx: mesh_idx, use_materials: !fields.has_material()}
+                        },
+                    };
+
+                    let texture_path = fields.take_str("texture");
+                    let wrap_mode = match fields.take_str("wrap") {
+                        None => WrapMode::Repeat,
+                        Some(_) if texture_path.is_none() => {
+                            return Err(fields.error("wrap", "the wrap mode requires a `texture`".to_string()));
+                        },
+                        Some(wrap) => WrapMode::from_name(&wrap)
+                            .ok_or(fields.error("wrap", format!("unknown wrap mode `{}`", wrap)))?,
+                    };
+                    let texture_idx = texture_path.map(|path| {
+                        let texture = (path, wrap_mode);
+
+                        match description.texture_paths.iter().position(|t| t == &texture) {
+                            Some(idx) => idx,
+                            None => {
+                                description.texture_paths.push(texture);
+                                description.texture_paths.len() - 1
+                            }
+                        }
+                    });
+
+                    let (transformation, end_transformation) = fields.take_transformations()?;
+
+                    description.objects.push(ObjectDescription {
+                        shape: shape,
+                        vis: fields.take_visual_data()?,
+                        transformation: transformation,
+                        end_transformation: end_transformation,
+                        texture_idx: texture_idx,
+                    });
+                },
+                _ => return Err(SceneParseError::new(line_num, None, format!("unknown statement `{}`", keyword))),
+            }
+
+            fields.finish()?;
+        }
+
+        Ok(description)
+    }
+
+    fn empty() -> Self {
+        let render_options = RenderOptions::defaults();
+
+        SceneDescription {
+            camera_opts: render_options.camera_opts,
+            fov: render_options.fov,
+            projection_type: render_options.projection_type,
+            lens: render_options.lens,
+            background_color: Color::from_srgb(0.204, 0.596, 0.86),
+            ambient_strength: 0.7,
+            diffuse_strength: 0.5,
+            lights: vec![],
+            objects: vec![],
+            mesh_paths: vec![],
+            meshes: vec![],
+            texture_paths: vec![],
+            textures: vec![],
+        }
+    }
+
+    pub fn load_meshes(&mut self, base_dir: &Path) -> Result<(), RtrsError> {
+        self.meshes = self.mesh_paths.iter()
+            .map(|path| {
+                let full_path = base_dir.join(path);
+
+                TriangleMesh::from_obj(&full_path.to_string_lossy(), VisualData::grey())
+            })
+            .collect::<Result<Vec<TriangleMesh>, RtrsError>>()?;
+
+        Ok(())
+    }
+
+    pub fn load_textures(&mut self, base_dir: &Path) -> Result<(), RtrsError> {
+        self.textures = self.texture_paths.iter()
+            .map(|(path, wrap_mode)| {
+                let full_path = base_dir.join(path);
+
+                Texture::from_file(&full_path.to_string_lossy(), *wrap_mode).map(Arc::new)
+            })
+            .collect::<Result<Vec<Arc<Texture>>, RtrsError>>()?;
+
+        Ok(())
+    }
+
+    pub fn apply_camera(&self, render_options: &mut RenderOptions) {
+        render_options.camera_opts = self.camera_opts.clone();
+        render_options.fov = self.fov;
+        render_options.projection_type = self.projection_type;
+        render_options.lens = self.lens;
+    }
+
+    // Fails if the meshes or textures have not been loaded
+    pub fn compute_scene(&self, render_options: &RenderOptions) -> Result<Scene, RtrsError> {
+        let objects = self.objects.iter()
+            .map(|object| {
+                let end_transformation = object.end_transformation.as_ref().filter(|_| render_options.use_motion_blur);
+
+                self.create_surface(object, &object.transformation, end_transformation)
+            })
+            .collect::<Result<Vec<Box<dyn Surface>>, RtrsError>>()?;
+
+        Ok(Scene::new(
+            objects,
+            render_options.compute_camera(),
+            self.background_color,
+            self.lights.clone(),
+            self.ambient_strength,
+            self.diffuse_strength,
+        ))
+    }
+
+    fn create_surface(
+        &self, object: &ObjectDescription, transformation: &AffineMat3,
+        end_transformation: Option<&AffineMat3>) -> Result<Box<dyn Surface>, RtrsError> {
+
+        let mut vis = object.vis.clone();
+
+        if let Some(texture_idx) = object.texture_idx {
+            vis.diffuse_texture = Some(self.textures.get(texture_idx)
+                .ok_or_else(|| match self.texture_paths.get(texture_idx) {
+                    Some((path, _)) => RtrsError::InvalidSceneConfig(format!("texture {} has not been loaded", path)),
+                    None => RtrsError::InvalidSceneConfig(format!("there is no texture with index {}", texture_idx)),
+                })?
+                .clone());
+        }
+
+        Ok(match &object.shape {
+            Shape::Sphere {center, radius} => transform_surface(transformation, end_transformation, Sphere {
+                center: center.clone(),
+                radius: *radius,
+                vis: vis,
+            }),
+            Shape::Plane {point, normal} => transform_surface(transformation, end_transformation, Plane {
+                bias: point.clone(),
+                normal: normal.clone(),
+                vis: vis,
+            }),
+            Shape::Ellipsoid {center, radii} => transform_surface(transformation, end_transformation, Ellipsoid {
+                center: center.clone(),
+                scale: DiagMat3 {a: radii.x, b: radii.y, c: radii.z},
+                vis: vis,
+            }),
+            Shape::Cone {apex, height, half_angle} => transform_surface(transformation, end_transformation, Cone {
+                apex: apex.clone(),
+                height: *height,
+                half_angle: *half_angle,
+                vis: vis,
+            }),
+            Shape::Mesh {path, mesh_idx, use_materials} => {
+                let mut mesh = self.meshes.get(*mesh_idx)
+                    .ok_or_else(|| RtrsError::InvalidSceneConfig(format!("mesh {} has not been loaded", path)))?
+                    .clone();
+                mesh.vis = vis;
+                mesh.use_materials = *use_materials;
+
+                transform_surface(transformation, end_transformation, mesh)
+            },
+        })
+    }
+}
+
+
+// The `camera` statement of the viewpoint, so the view of an interactive viewer can be put into a scene file
+pub fn format_camera_statement(camera_opts: &CameraOptions, fov: f32) -> String {
+    let position = &camera_opts.position;
+
+    format!(
+        "camera position={},{},{} yaw={} pitch={} roll={} fov={}",
+        position.x, position.y, position.z,
+        camera_opts.yaw.to_degrees(), camera_opts.pitch.to_degrees(), camera_opts.roll.to_degrees(), fov.to_degrees(),
+    )
+}
+
+
+fn transform_surface<S: Surface + 'static>(
+    transformation: &AffineMat3, end_transformation: Option<&AffineMat3>, surface: S) -> Box<dyn Surface> {
+
+    let transformed_surface = TransformedSurface::new(transformation.clone(), surface);
+
+    match end_transformation {
+        Some(end_transformation) => Box::new(transformed_surface.with_end_transformation(end_transformation.clone())),
+        None => Box::new(transformed_surface),
+    }
+}
+
+
+static MATERIAL_FIELDS: [&str; 10] = [
+    "color", "specular", "shininess", "reflection", "glossiness", "ior", "transparency", "absorption", "texture", "wrap",
+];
+
+
+struct Fields {
+    line: usize,
+    values: HashMap<String, String>,
+}
+
+
+impl Fields {
+    fn parse<'a>(line: usize, tokens: impl Iterator<Item = &'a str>) -> Result<Fields, SceneParseError> {
+        let mut values = HashMap::new();
+
+        for token in tokens {
+            let mut parts = token.splitn(2, '=');
+            let key = parts.next().unwrap();
+            let value = parts.next()
+                .ok_or(SceneParseError::new(line, Some(key), "expected `key=value`".to_string()))?;
+
+            if values.insert(key.to_string(), value.to_string()).is_some() {
+                return Err(SceneParseError::new(line, Some(key), "field is specified twice".to_string()));
+            }
+        }
+
+        Ok(Fields {line: line, values: values})
+    }
+
+    fn error(&self, field: &str, message: String) -> SceneParseError {
+        SceneParseError::new(self.line, Some(field), message)
+    }
+
+    fn take_str(&mut self, field: &str) -> Option<String> {
+        self.values.remove(field)
+    }
+
+    fn take_floats(&mut self, field: &str) -> Result<Option<Vec<f32>>, SceneParseError> {
+        match self.values.remove(field) {
+            None => Ok(None),
+            Some(value) => value.split(',')
+                .map(|v| v.parse::<f32>().map_err(|_| self.error(field, format!("`{}` is not a number", v))))
+                .collect::<Result<Vec<f32>, SceneParseError>>()
+                .map(Some),
+        }
+    }
+
+    fn take_f32(&mut self, field: &str) -> Result<Option<f32>, SceneParseError> {
+        match self.take_floats(field)? {
+            None => Ok(None),
+            Some(values) if values.len() == 1 => Ok(Some(values[0])),
+            Some(values) => Err(self.error(field, format!("expected a single number, got {} values", values.len()))),
+        }
+    }
+
+    fn take_vec3(&mut self, field: &str) -> Result<Option<Vec3>, SceneParseError> {
+        match self.take_floats(field)? {
+            None => Ok(None),
+            Some(values) if values.len() == 3 => Ok(Some(Vec3::new(values[0], values[1], values[2]))),
+            Some(values) => Err(self.error(field, format!("expected 3 comma-separated numbers, got {}", values.len()))),
+        }
+    }
+
+    fn require_vec3(&mut self, field: &str) -> Result<Vec3, SceneParseError> {
+        self.take_vec3(field)?.ok_or(self.error(field, "field is required".to_string()))
+    }
+
+    fn take_color(&mut self, field: &str) -> Result<Option<Color>, SceneParseError> {
+        Ok(self.take_vec3(field)?.map(|v| Color::from_srgb(v.x, v.y, v.z)))
+    }
+
+    fn require_color(&mut self, field: &str) -> Result<Color, SceneParseError> {
+        self.take_color(field)?.ok_or(self.error(field, "field is required".to_string()))
+    }
+
+    fn has_material(&self) -> bool {
+        MATERIAL_FIELDS.iter().any(|field| self.values.contains_key(*field))
+    }
+
+    fn take_visual_data(&mut self) -> Result<VisualData, SceneParseError> {
+        let mut vis = match self.take_color("color")? {
+            Some(color) => VisualData::from_color(&color),
+            None => VisualData::grey(),
+        };
+
+        vis.specular_strength = self.take_f32("specular")?.unwrap_or(0.0);
+        vis.shininess = self.take_f32("shininess")?.unwrap_or(vis.shininess);
+        vis.reflection_strength = self.take_f32("reflection")?.unwrap_or(0.0);
+        vis.reflection_glossiness = self.take_f32("glossiness")?.unwrap_or(0.0);
+        vis.refractive_index = self.take_f32("ior")?.unwrap_or(1.0);
+        vis.transparency = self.take_f32("transparency")?.unwrap_or(0.0);
+
+        if let Some(absorption) = self.take_vec3("absorption")? {
+            // Absorption coefficients are not colors and can be larger than 1
+            vis.absorption = Color {r: absorption.x, g: absorption.y, b: absorption.z};
+        }
+
+        Ok(vis)
+    }
+
+    fn take_lens(&mut self) -> Result<Option<ThinLens>, SceneParseError> {
+        let aperture_shape = match self.take_f32("blades")? {
+            None => ApertureShape::Disc,
+            Some(num_blades) if num_blades >= 3.0 && num_blades.fract() == 0.0 => ApertureShape::Polygon(num_blades as u32),
+            Some(_) => return Err(self.error("blades", "expected an integer of at least 3".to_string())),
+        };
+
+        match (self.take_f32("aperture")?, self.take_f32("focus")?) {
+            (Some(aperture_radius), _) if aperture_radius <= 0.0 || aperture_radius.is_nan() => {
+                Err(self.error("aperture", "the aperture radius must be positive".to_string()))
+            },
+            (_, Some(focal_distance)) if focal_distance <= 0.0 || focal_distance.is_nan() => {
+                Err(self.error("focus", "the focus distance must be positive".to_string()))
+            },
+            (Some(aperture_radius), Some(focal_distance)) => Ok(Some(ThinLens {
+                aperture_radius: aperture_radius,
+                focal_distance: focal_distance,
+                aperture_shape: aperture_shape,
+            })),
+            (None, None) => Ok(None),
+            _ => Err(self.error("aperture", "the `aperture` radius and the `focus` distance go together".to_string())),
+        }
+    }
+
+    fn take_scale(&mut self, field: &str) -> Result<Option<AffineMat3>, SceneParseError> {
+        match self.take_floats(field)? {
+            None => Ok(None),
+            Some(values) if values.len() == 1 => Ok(Some(AffineMat3::scale(Vec3::new(values[0], values[0], values[0])))),
+            Some(values) if values.len() == 3 => Ok(Some(AffineMat3::scale(Vec3::new(values[0], values[1], values[2])))),
+            Some(_) => Err(self.error(field, "expected either 1 or 3 comma-separated numbers".to_string())),
+        }
+    }
+
+    fn take_rotation(&mut self, field: &str) -> Result<Option<AffineMat3>, SceneParseError> {
+        match self.take_floats(field)? {
+            None => Ok(None),
+            Some(values) if values.len() == 4 => {
+                let axis = Vec3::new(values[1], values[2], values[3]);
+
+                if axis.norm_squared() == 0.0 {
+                    return Err(self.error(field, "rotation axis must be non-zero".to_string()));
+                }
+
+                Ok(Some(AffineMat3::rotation(values[0].to_radians(), &axis)))
+            },
+            Some(_) => Err(self.error(field, "expected `angle,x,y,z`".to_string())),
+        }
+    }
+
+    // Returns the transformation and, if any of the `end_` fields is given, the one at the shutter closing
+    fn take_transformations(&mut self) -> Result<(AffineMat3, Option<AffineMat3>), SceneParseError> {
+        let scale = self.take_scale("scale")?.unwrap_or(AffineMat3::identity());
+        let rotation = self.take_rotation("rotate")?.unwrap_or(AffineMat3::identity());
+        let translation = AffineMat3::translation(self.take_vec3("translate")?.unwrap_or(Vec3::zero()));
+
+        let end_scale = self.take_scale("end_scale")?;
+        let end_rotation = self.take_rotation("end_rotate")?;
+        let end_translation = self.take_vec3("end_translate")?.map(AffineMat3::translation);
+
+        let end_transformation = if end_scale.is_none() && end_rotation.is_none() && end_translation.is_none() {
+            None
+        } else {
+            let end_scale = end_scale.unwrap_or(scale.clone());
+            let end_rotation = end_rotation.unwrap_or(rotation.clone());
+            let end_translation = end_translation.unwrap_or(translation.clone());
+
+            Some(&end_translation * &(&end_rotation * &end_scale))
+        };
+
+        Ok((&translation * &(&rotation * &scale), end_transformation))
+    }
+
+    fn finish(self) -> Result<(), SceneParseError> {
+        let mut unknown_fields = self.values.keys().collect::<Vec<&String>>();
+        unknown_fields.sort();
+
+        match unknown_fields.first() {
+            Some(field) => Err(self.error(field, "unknown field".to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod scene_file_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scene() {
+        let description = SceneDescription::parse("
+            # A simple scene
+            camera position=0,1,-5 yaw=-90 fov=60
+            lighting ambient=0.3 diffuse=0.6
+            light location=0,10,0 right=0.2,0,0 top=0,0,0.2
+            plane point=0,-1,0
+            sphere color=1,0,0 reflection=0.5 translate=1,0,0 scale=0.5
+            mesh path=teapot.obj texture=wood.png
+            mesh path=teapot.obj scale=2 texture=wood.png wrap=clamp
+        ").unwrap();
+
+        assert_eq!(description.camera_opts.position, Vec3::new(0.0, 1.0, -5.0));
+        assert!(approx_eq!(f32, description.fov, 60.0_f32.to_radians()));
+        assert!(approx_eq!(f32, description.ambient_strength, 0.3));
+        assert_eq!(description.lights.len(), 1);
+        assert_eq!(description.objects.len(), 4);
+        assert_eq!(description.mesh_paths, vec!["teapot.obj".to_string()]);
+        assert_eq!(description.texture_paths.len(), 2);
+        assert_eq!(description.objects[3].texture_idx, Some(1));
+        assert!(approx_eq!(f32, description.objects[1].vis.reflection_strength, 0.5));
+        assert!(approx_eq!(f32, description.objects[1].transformation.transform_mat[0][0], 0.5));
+        assert!(approx_eq!(f32, description.objects[1].transformation.translation.x, 1.0));
+        assert!(description.objects[1].end_transformation.is_none());
+
+        // The .mtl materials are used only if the mesh has no material fields
+        let uses_materials = |statement: &str| match &SceneDescription::parse(statement).unwrap().objects[0].shape {
+            Shape::Mesh {path: _, mesh_idx: _, use_materials} => *use_materials,
+            _ => panic!("expected a mesh"),
+        };
+        assert!(uses_materials("mesh path=teapot.obj scale=2"));
+        assert!(!uses_materials("mesh path=teapot.obj color=1,1,1"));
+
+        // The scale is kept until the shutter closes
+        let end_transformation = SceneDescription::parse("sphere scale=0.5 translate=1,0,0 end_translate=2,0,0")
+            .unwrap().objects[0].end_transformation.clone().unwrap();
+        assert!(approx_eq!(f32, end_transformation.transform_mat[0][0], 0.5));
+        assert!(approx_eq!(f32, end_transformation.translation.x, 2.0));
+    }
+
+    #[test]
+    fn test_parse_colors() {
+        let description = SceneDescription::parse("
+            background color=0.5,0.5,0.5
+            light location=0,10,0 color=2,2,2
+            sphere color=0.5,0,1
+        ").unwrap();
+
+        // Material and background colors are decoded from sRGB, light intensities are kept as they are
+        assert!(approx_eq!(f32, description.background_color.r, 0.214, epsilon = 0.001));
+        assert!(approx_eq!(f32, description.objects[0].vis.color.r, 0.214, epsilon = 0.001));
+        assert!(approx_eq!(f32, description.objects[0].vis.color.b, 1.0));
+        assert!(approx_eq!(f32, description.lights[0].color.r, 2.0));
+    }
+
+    #[test]
+    fn test_camera_target() {
+        let description = SceneDescription::parse("camera position=0,5,-5 target=0,0,0 roll=10 aperture=0.1 focus=7 blades=6").unwrap();
+        let view_direction = description.camera_opts.compute_view_direction();
+
+        assert!(approx_eq!(f32, view_direction.x, 0.0, epsilon = 0.00001));
+        assert!(approx_eq!(f32, view_direction.y, -0.5_f32.sqrt(), epsilon = 0.00001));
+        assert!(approx_eq!(f32, view_direction.z, 0.5_f32.sqrt(), epsilon = 0.00001));
+        assert!(approx_eq!(f32, description.camera_opts.roll, 10.0_f32.to_radians()));
+        assert_eq!(description.lens.unwrap().aperture_shape, ApertureShape::Polygon(6));
+        assert!(SceneDescription::parse("camera aperture=0.1").is_err());
+
+        let err = SceneDescription::parse("camera aperture=0 focus=7").unwrap_err();
+        assert_eq!(err.field, Some("aperture".to_string()));
+
+        let err = SceneDescription::parse("camera aperture=0.1 focus=-7").unwrap_err();
+        assert_eq!(err.field, Some("focus".to_string()));
+
+        // The printed statement gives the same viewpoint
+        let statement = format_camera_statement(&description.camera_opts, 1.0);
+        let parsed = SceneDescription::parse(&statement).unwrap();
+        assert_eq!(parsed.camera_opts.position, description.camera_opts.position);
+        assert!(approx_eq!(f32, parsed.camera_opts.yaw, description.camera_opts.yaw, epsilon = 0.00001));
+        assert!(approx_eq!(f32, parsed.camera_opts.pitch, description.camera_opts.pitch, epsilon = 0.00001));
+        assert!(approx_eq!(f32, parsed.camera_opts.roll, description.camera_opts.roll, epsilon = 0.00001));
+        assert!(approx_eq!(f32, parsed.fov, 1.0, epsilon = 0.00001));
+    }
+
+    #[test]
+    fn test_missing_textures() {
+        let mut description = SceneDescription::parse("sphere texture=wood.png").unwrap();
+        assert!(description.compute_scene(&RenderOptions::defaults()).is_err());
+
+        // An inconsistent description is reported as well instead of panicking
+        description.texture_paths.clear();
+        assert!(description.compute_scene(&RenderOptions::defaults()).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = SceneParseError::new(2, Some("radius"), "`big` is not a number".to_string());
+        assert_eq!(SceneDescription::parse("\nsphere radius=big").unwrap_err(), err);
+
+        let err = SceneDescription::parse("sphere\nsphere colour=1,0,0").unwrap_err();
+        assert_eq!((err.line, err.field), (2, Some("colour".to_string())));
+
+        let err = SceneDescription::parse("light location=1,2").unwrap_err();
+        assert_eq!((err.line, err.field), (1, Some("location".to_string())));
+
+        let err = SceneDescription::parse("mesh scale=2").unwrap_err();
+        assert_eq!(err.field, Some("path".to_string()));
+
+        let err = SceneDescription::parse("sphere texture=checker.png wrap=tile").unwrap_err();
+        assert_eq!(err.field, Some("wrap".to_string()));
+
+        let err = SceneDescription::parse("sphere wrap=clamp").unwrap_err();
+        assert_eq!(err.field, Some("wrap".to_string()));
+
+        let err = SceneDescription::parse("plane normal=0,0,0").unwrap_err();
+        assert_eq!(err.field, Some("normal".to_string()));
+
+        let err = SceneDescription::parse("torus").unwrap_err();
+        assert_eq!((err.line, err.field), (1, None));
+    }
+}
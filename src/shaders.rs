@@ -0,0 +1,348 @@
+// The built-in shaders of the rasterizer. The flat, Gouraud and Phong shaders only differ in the
+// normals they use and in where the lighting is computed, while the texture and stripe shaders
+// wrap any of them and modulate the color with the texture coordinates
+use std::sync::Arc;
+
+use crate::basics::{Color, Point, Vec3};
+use crate::matrix::{AffineMat3, Mat3};
+use crate::clipping::{ClipVertex, Interpolate};
+use crate::pipeline::{VertexShader, FragmentShader, VertexInput, Projection};
+use crate::texture::Texture;
+
+
+// Phong reflection model with a single point light
+#[derive(Debug, Clone)]
+pub struct Lighting {
+    pub light_position: Point, // In the camera space
+    pub ambient: f32,
+    pub diffuse: f32,
+    pub specular: f32,
+    pub shininess: i32,
+}
+
+
+// The values which all the built-in lighting shaders need
+#[derive(Debug, Clone)]
+pub struct ShaderUniforms {
+    pub object_to_camera: AffineMat3,
+    pub normal_to_camera: Mat3, // The inverse transpose of the linear part of `object_to_camera`
+    pub projection: Projection,
+    pub lighting: Lighting,
+    pub color: Color,
+}
+
+
+// Lit with the face normal at the vertices, so each triangle has almost a constant color
+#[derive(Debug, Clone)]
+pub struct FlatShader {
+    pub uniforms: ShaderUniforms,
+}
+
+
+// Lit with the vertex normals at the vertices, the intensity is interpolated over the triangle
+#[derive(Debug, Clone)]
+pub struct GouraudShader {
+    pub uniforms: ShaderUniforms,
+}
+
+
+// The normals are interpolated over the triangle and lit for each fragment
+#[derive(Debug, Clone)]
+pub struct PhongShader {
+    pub uniforms: ShaderUniforms,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct PhongVaryings {
+    pub position_camera: Point,
+    pub normal_camera: Vec3,
+}
+
+
+// Multiplies the color of the base shader by the texture
+#[derive(Debug, Clone)]
+pub struct TextureShader<S> {
+    pub base: S,
+    pub texture: Arc<Texture>,
+}
+
+
+// Draws procedural stripes along the `v` texture coordinate over the base shader
+#[derive(Debug, Clone)]
+pub struct StripeShader<S> {
+    pub base: S,
+    pub color: Color,
+}
+
+
+impl Lighting {
+    pub fn new(light_position: Point) -> Lighting {
+        Lighting {
+            light_position: light_position,
+            ambient: 0.1,
+            diffuse: 1.0,
+            specular: 0.5,
+            shininess: 32,
+        }
+    }
+
+    // The intensity is not clamped, so it can be interpolated between the vertices
+    pub fn compute_intensity(&self, position_camera: &Point, normal_camera: &Vec3) -> f32 {
+        let light_dir = (&self.light_position - position_camera).normalize();
+        let normal_dot_light = normal_camera.dot_product(&light_dir);
+        let mut intensity = self.ambient + self.diffuse * normal_dot_light;
+
+        if self.specular > 0.0 {
+            let view_dir = (-&Vec3::from(position_camera)).normalize();
+            let reflect_dir = &(-&light_dir) + &(normal_camera * (2.0 * normal_dot_light.max(0.0)));
+
+            intensity += self.specular * view_dir.dot_product(&reflect_dir).max(0.0).powi(self.shininess);
+        }
+
+        intensity
+    }
+}
+
+
+impl ShaderUniforms {
+    pub fn new(object_to_camera: AffineMat3, projection: Projection, lighting: Lighting, color: Color) -> ShaderUniforms {
+        ShaderUniforms {
+            normal_to_camera: object_to_camera.compute_inverse().transform_mat.transpose(),
+            object_to_camera: object_to_camera,
+            projection: projection,
+            lighting: lighting,
+            color: color,
+        }
+    }
+
+    fn light_vertex(&self, position: &Point, normal: &Vec3) -> ClipVertex<f32> {
+        let position_camera = &self.object_to_camera * position;
+        let normal_camera = (&self.normal_to_camera * normal).normalize();
+
+        ClipVertex {
+            position: self.projection.project(&position_camera),
+            attributes: self.lighting.compute_intensity(&position_camera, &normal_camera),
+        }
+    }
+
+    fn shade_intensity(&self, intensity: f32) -> Color {
+        &self.color * intensity.max(0.0)
+    }
+}
+
+
+impl VertexShader for FlatShader {
+    type Varyings = f32;
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<f32> {
+        self.uniforms.light_vertex(&vertex.position, &vertex.face_normal)
+    }
+}
+
+
+impl FragmentShader<f32> for FlatShader {
+    fn shade_fragment(&self, intensity: &f32) -> Color {
+        self.uniforms.shade_intensity(*intensity)
+    }
+}
+
+
+impl VertexShader for GouraudShader {
+    type Varyings = f32;
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<f32> {
+        self.uniforms.light_vertex(&vertex.position, &vertex.normal)
+    }
+}
+
+
+impl FragmentShader<f32> for GouraudShader {
+    fn shade_fragment(&self, intensity: &f32) -> Color {
+        self.uniforms.shade_intensity(*intensity)
+    }
+}
+
+
+impl Interpolate for PhongVaryings {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        PhongVaryings {
+            position_camera: self.position_camera.interpolate(&other.position_camera, t),
+            normal_camera: self.normal_camera.interpolate(&other.normal_camera, t),
+        }
+    }
+}
+
+
+impl VertexShader for PhongShader {
+    type Varyings = PhongVaryings;
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<PhongVaryings> {
+        let position_camera = &self.uniforms.object_to_camera * &vertex.position;
+
+        ClipVertex {
+            position: self.uniforms.projection.project(&position_camera),
+            attributes: PhongVaryings {
+                normal_camera: &self.uniforms.normal_to_camera * &vertex.normal,
+                position_camera: position_camera,
+            },
+        }
+    }
+}
+
+
+impl FragmentShader<PhongVaryings> for PhongShader {
+    fn shade_fragment(&self, varyings: &PhongVaryings) -> Color {
+        let normal_camera = varyings.normal_camera.normalize();
+        let intensity = self.uniforms.lighting.compute_intensity(&varyings.position_camera, &normal_camera);
+
+        self.uniforms.shade_intensity(intensity)
+    }
+}
+
+
+impl<S: VertexShader> VertexShader for TextureShader<S> {
+    type Varyings = (S::Varyings, (f32, f32));
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<Self::Varyings> {
+        let base_vertex = self.base.shade_vertex(vertex);
+
+        ClipVertex {position: base_vertex.position, attributes: (base_vertex.attributes, vertex.texcoords)}
+    }
+}
+
+
+impl<S: FragmentShader<V>, V> FragmentShader<(V, (f32, f32))> for TextureShader<S> {
+    fn shade_fragment(&self, varyings: &(V, (f32, f32))) -> Color {
+        let (u, v) = varyings.1;
+
+        &self.base.shade_fragment(&varyings.0) * &self.texture.sample(u, v)
+    }
+}
+
+
+impl<S: VertexShader> VertexShader for StripeShader<S> {
+    type Varyings = (S::Varyings, (f32, f32));
+
+    fn shade_vertex(&self, vertex: &VertexInput) -> ClipVertex<Self::Varyings> {
+        let base_vertex = self.base.shade_vertex(vertex);
+
+        ClipVertex {position: base_vertex.position, attributes: (base_vertex.attributes, vertex.texcoords)}
+    }
+}
+
+
+impl<S: FragmentShader<V>, V> FragmentShader<(V, (f32, f32))> for StripeShader<S> {
+    fn shade_fragment(&self, varyings: &(V, (f32, f32))) -> Color {
+        let (u, v) = varyings.1;
+
+        &self.base.shade_fragment(&varyings.0) + &(&self.color * compute_stripe_intensity(u, v))
+    }
+}
+
+
+fn compute_stripe_intensity(_u: f32, v: f32) -> f32 {
+    let stripes_fuzz = 0.1;
+    let stripes_width = 0.5;
+    let stripes_scale = 20.0;
+
+    let scaled_v = (v * stripes_scale) % 1.0; // Split into 20 stripes
+    let step_1 = (scaled_v / stripes_fuzz).clamp(0.0, 1.0);
+    let step_2 = ((scaled_v - stripes_width) / stripes_fuzz).clamp(0.0, 1.0);
+    let step_3 = step_1 * (1.0 - step_2);
+    let step_4 = step_3 * step_3 * (3.0 - (2.0 * step_3));
+
+    let back_color = 0.0;
+    let stripe_color = 0.7;
+
+    back_color * step_4 + (1.0 - step_4) * stripe_color
+}
+
+
+#[cfg(test)]
+mod shaders_tests {
+    use super::*;
+    use crate::camera::ViewingPlane;
+
+    fn create_uniforms() -> ShaderUniforms {
+        create_uniforms_with(AffineMat3::translation(Vec3::new(0.0, 0.0, -5.0)))
+    }
+
+    fn create_uniforms_with(object_to_camera: AffineMat3) -> ShaderUniforms {
+        ShaderUniforms::new(
+            object_to_camera,
+            Projection {
+                viewing_plane: ViewingPlane::from_fov(std::f32::consts::PI * 0.5, 100, 100),
+                near_clipping_plane: 0.1,
+                far_clipping_plane: 100.0,
+            },
+            Lighting::new(Point::new(0.0, 0.0, 0.0)),
+            Color::new(1.0, 0.5, 0.0),
+        )
+    }
+
+    #[test]
+    fn test_lighting() {
+        let lighting = Lighting::new(Point::new(0.0, 0.0, 0.0));
+        let position = Point::new(0.0, 0.0, -5.0);
+
+        // Facing the light, which is at the camera, so the highlight is at its brightest
+        let facing = lighting.compute_intensity(&position, &Vec3::new(0.0, 0.0, 1.0));
+        assert!(approx_eq!(f32, facing, 0.1 + 1.0 + 0.5, epsilon = 0.0001));
+
+        // Facing away, which is clamped only by the fragment shaders
+        let away = lighting.compute_intensity(&position, &Vec3::new(0.0, 0.0, -1.0));
+        assert!(approx_eq!(f32, away, 0.1 - 1.0, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn test_lighting_shaders() {
+        let vertex = VertexInput {
+            position: Point::new(0.0, 0.0, 0.0),
+            normal: Vec3::new(1.0, 0.0, 0.0),
+            face_normal: Vec3::new(0.0, 0.0, 1.0),
+            texcoords: (0.5, 0.5),
+        };
+        let flat = FlatShader {uniforms: create_uniforms()};
+        let gouraud = GouraudShader {uniforms: create_uniforms()};
+        let phong = PhongShader {uniforms: create_uniforms()};
+
+        // The flat shader uses the face normal, which faces the light
+        let flat_vertex = flat.shade_vertex(&vertex);
+        assert!(approx_eq!(f32, flat_vertex.position.w, 5.0));
+        assert!(approx_eq!(f32, flat.shade_fragment(&flat_vertex.attributes).g, 0.8, epsilon = 0.0001));
+
+        // The vertex normal is orthogonal to the light direction, so only the ambient term is left
+        let gouraud_vertex = gouraud.shade_vertex(&vertex);
+        assert!(approx_eq!(f32, gouraud.shade_fragment(&gouraud_vertex.attributes).r, 0.1, epsilon = 0.0001));
+
+        let phong_vertex = phong.shade_vertex(&vertex);
+        assert!(approx_eq!(f32, phong.shade_fragment(&phong_vertex.attributes).r, 0.1, epsilon = 0.0001));
+
+        // The wrapped shaders add the texture coordinates to the varyings of the base one
+        let texture = Arc::new(Texture::new(1, 1, vec![Color::new(0.5, 0.5, 0.5)], crate::texture::WrapMode::Repeat));
+        let textured = TextureShader {base: flat.clone(), texture: texture};
+        let textured_vertex = textured.shade_vertex(&vertex);
+        assert_eq!(textured_vertex.attributes.1, (0.5, 0.5));
+        assert!(approx_eq!(f32, textured.shade_fragment(&textured_vertex.attributes).g, 0.4, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn test_non_uniform_scale_normals() {
+        // The plane x + y = 0 squashed along x, its normal has to stay orthogonal to the transformed plane
+        let object_to_camera = &AffineMat3::translation(Vec3::new(0.0, 0.0, -5.0)) * &AffineMat3::scale(Vec3::new(0.25, 1.0, 1.0));
+        let uniforms = create_uniforms_with(object_to_camera.clone());
+        let normal = Vec3::new(1.0, 1.0, 0.0).normalize();
+        let tangent = &object_to_camera * &Vec3::new(1.0, -1.0, 0.0);
+        let vertex = VertexInput {
+            position: Point::new(0.0, 0.0, 0.0),
+            normal: normal.clone(),
+            face_normal: normal,
+            texcoords: (0.0, 0.0),
+        };
+
+        let phong = PhongShader {uniforms: uniforms};
+        let normal_camera = phong.shade_vertex(&vertex).attributes.normal_camera;
+        assert!(approx_eq!(f32, normal_camera.dot_product(&tangent), 0.0, epsilon = 0.00001));
+    }
+}
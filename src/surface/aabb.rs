@@ -15,6 +15,104 @@ pub struct AxisAlignedBox {
     pub max_corner: Point,
 }
 
+impl AxisAlignedBox {
+    // The box which contains nothing, growing it by a point gives the box of that point
+    pub fn empty() -> Self {
+        AxisAlignedBox {
+            min_corner: Point::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+            max_corner: Point::new(-f32::INFINITY, -f32::INFINITY, -f32::INFINITY),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_corner.x > self.max_corner.x
+            || self.min_corner.y > self.max_corner.y
+            || self.min_corner.z > self.max_corner.z
+    }
+
+    pub fn grow(&self, point: &Point) -> Self {
+        AxisAlignedBox {
+            min_corner: Point::new(
+                self.min_corner.x.min(point.x),
+                self.min_corner.y.min(point.y),
+                self.min_corner.z.min(point.z),
+            ),
+            max_corner: Point::new(
+                self.max_corner.x.max(point.x),
+                self.max_corner.y.max(point.y),
+                self.max_corner.z.max(point.z),
+            ),
+        }
+    }
+
+    pub fn union(&self, other: &AxisAlignedBox) -> Self {
+        AxisAlignedBox {
+            min_corner: Point::new(
+                self.min_corner.x.min(other.min_corner.x),
+                self.min_corner.y.min(other.min_corner.y),
+                self.min_corner.z.min(other.min_corner.z),
+            ),
+            max_corner: Point::new(
+                self.max_corner.x.max(other.max_corner.x),
+                self.max_corner.y.max(other.max_corner.y),
+                self.max_corner.z.max(other.max_corner.z),
+            ),
+        }
+    }
+
+    // The box which contains all the 8 transformed corners
+    pub fn transform(&self, transformation: &AffineMat3) -> Self {
+        let corners = iproduct!(0..2, 0..2, 0..2).map(|(i, j, k)| Point::new(
+            if i == 0 {self.min_corner.x} else {self.max_corner.x},
+            if j == 0 {self.min_corner.y} else {self.max_corner.y},
+            if k == 0 {self.min_corner.z} else {self.max_corner.z},
+        ));
+
+        corners.fold(AxisAlignedBox::empty(), |bbox, corner| bbox.grow(&(transformation * &corner)))
+    }
+
+    pub fn compute_center(&self) -> Point {
+        &self.min_corner + &(&(&self.max_corner - &self.min_corner) * 0.5)
+    }
+
+    pub fn compute_extent(&self, axis: usize) -> f32 {
+        (self.max_corner[axis] - self.min_corner[axis]).max(0.0)
+    }
+
+    // Slab test which returns the distance at which the ray enters the box (zero if it starts inside).
+    // `inv_direction` is the componentwise inverse of the ray direction
+    pub fn compute_entry_t(&self, ray: &Ray, inv_direction: &Vec3, t_max: f32) -> Option<f32> {
+        let mut t_near = -f32::INFINITY;
+        let mut t_far = f32::INFINITY;
+
+        for axis in 0..3 {
+            let t_0 = (self.min_corner[axis] - ray.origin[axis]) * inv_direction[axis];
+            let t_1 = (self.max_corner[axis] - ray.origin[axis]) * inv_direction[axis];
+
+            // NaNs appear when the ray lies in a slab plane, `min` and `max` ignore them
+            t_near = t_near.max(t_0.min(t_1));
+            t_far = t_far.min(t_0.max(t_1));
+        }
+
+        if t_near > t_far || t_far < MIN_RAY_T || t_near >= t_max {
+            None
+        } else {
+            Some(t_near.max(0.0))
+        }
+    }
+
+    pub fn compute_surface_area(&self) -> f32 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        let (dx, dy, dz) = (self.compute_extent(0), self.compute_extent(1), self.compute_extent(2));
+
+        2.0 * (dx * dy + dy * dz + dz * dx)
+    }
+}
+
+
 impl Surface for AxisAlignedBox {
     fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
         let mut t_min = (self.min_corner.x - ray.origin.x) / (ray.direction.x + EPSILON);
@@ -75,9 +173,11 @@ impl Surface for AxisAlignedBox {
         }
 
         // Returning the dummy normal since we are not going to render it anyway
-        Some(Hit {t: t, normal: Vec3 {x: 0.0, y: 1.0, z: 0.0}})
+        Some(Hit::new(t, Vec3 {x: 0.0, y: 1.0, z: 0.0}))
     }
     fn get_visual_data(&self) -> VisualData { VisualData::grey() }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> { Some(self.clone()) }
 }
 
 
@@ -94,10 +194,37 @@ mod box_tests {
         let ray = Ray {
             origin: Point {x: 0.0, y: 0.0, z: -1.0},
             direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
+            time: 0.0,
         };
 
         println!("Hit: {:?}", aab.compute_hit(&ray, RayOptions::from_depth(0)));
         let t = aab.compute_hit(&ray, RayOptions::from_depth(0)).unwrap().t;
         assert!(approx_eq!(f32, t, 1.0));
     }
+
+    #[test]
+    fn test_box_union() {
+        let aab = AxisAlignedBox::empty()
+            .grow(&Point::new(0.0, 0.0, 0.0))
+            .union(&AxisAlignedBox::empty().grow(&Point::new(1.0, 2.0, 3.0)));
+
+        assert!(AxisAlignedBox::empty().is_empty());
+        assert!(approx_eq!(f32, aab.union(&AxisAlignedBox::empty()).compute_surface_area(), 22.0));
+        assert_eq!(AxisAlignedBox::empty().compute_surface_area(), 0.0);
+        assert!(approx_eq!(f32, aab.compute_surface_area(), 22.0));
+        assert!(approx_eq!(f32, aab.compute_center().z, 1.5));
+    }
+
+    #[test]
+    fn test_box_transform() {
+        let aab = AxisAlignedBox {
+            min_corner: Point {x: -1.0, y: -1.0, z: -1.0},
+            max_corner: Point {x: 1.0, y: 1.0, z: 1.0},
+        };
+        let rotation = AffineMat3::rotation(std::f32::consts::PI / 4.0, &Vec3::new(0.0, 1.0, 0.0));
+        let rotated = aab.transform(&rotation);
+
+        assert!(approx_eq!(f32, rotated.max_corner.x, 2.0_f32.sqrt(), epsilon = 0.0001));
+        assert!(approx_eq!(f32, rotated.max_corner.y, 1.0, epsilon = 0.0001));
+    }
 }
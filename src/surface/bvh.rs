@@ -0,0 +1,588 @@
+use std::fmt;
+
+use crate::basics::*;
+use crate::surface::surface::Hit;
+use crate::surface::aabb::AxisAlignedBox;
+use crate::surface::MIN_RAY_T;
+
+
+// Costs of the surface area heuristic, relative to each other
+static TRAVERSAL_COST: f32 = 1.0;
+static INTERSECTION_COST: f32 = 1.0;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BvhSplitMethod {Median, Sah}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct BvhBuildOptions {
+    pub split_method: BvhSplitMethod,
+    pub max_leaf_size: usize,
+    pub num_bins: usize,
+}
+
+
+impl BvhBuildOptions {
+    pub fn defaults() -> Self {
+        BvhBuildOptions {
+            split_method: BvhSplitMethod::Sah,
+            max_leaf_size: 4,
+            num_bins: 16,
+        }
+    }
+}
+
+
+// A node of the tree which is produced by the builder. Leaves keep the indices
+// of their primitives, inner nodes always have both children
+#[derive(Debug, Clone)]
+pub struct BvhBuildNode {
+    pub bbox: AxisAlignedBox,
+    pub primitives: Vec<usize>,
+    pub children: Option<Box<(BvhBuildNode, BvhBuildNode)>>,
+}
+
+
+// Flattened hierarchy which is used for the traversal. Nodes are stored in the depth first order,
+// so the first child of an inner node goes right after it. Primitives of every leaf are contiguous
+#[derive(Debug, Clone)]
+pub struct Bvh {
+    pub nodes: Vec<BvhNode>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct BvhNode {
+    pub bbox: AxisAlignedBox,
+    pub sphere_center: Point,
+    pub sphere_radius: f32,
+    // The first primitive for leaves and the second child for inner nodes
+    pub offset: usize,
+    pub num_primitives: usize, // Zero for inner nodes
+    pub level: i32,
+}
+
+
+#[derive(Debug, Clone)]
+struct BuildPrimitive {
+    idx: usize,
+    bbox: AxisAlignedBox,
+    center: Point,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct BvhStats {
+    pub num_primitives: usize,
+    pub num_nodes: usize,
+    pub num_leaves: usize,
+    pub max_depth: usize,
+    pub max_leaf_size: usize,
+    pub sah_cost: f32,
+}
+
+
+// Builds a hierarchy over the primitives with the given bounding boxes
+pub fn build_bvh(bboxes: &[AxisAlignedBox], options: &BvhBuildOptions) -> BvhBuildNode {
+    assert!(bboxes.len() > 0);
+
+    let mut primitives = bboxes.iter().enumerate().map(|(idx, bbox)| BuildPrimitive {
+        idx: idx,
+        bbox: bbox.clone(),
+        center: bbox.compute_center(),
+    }).collect::<Vec<BuildPrimitive>>();
+
+    build_node(&mut primitives, 0, options)
+}
+
+
+fn build_node(primitives: &mut [BuildPrimitive], axis: usize, options: &BvhBuildOptions) -> BvhBuildNode {
+    let bbox = primitives.iter().fold(AxisAlignedBox::empty(), |bbox, p| bbox.union(&p.bbox));
+    let num_primitives = primitives.len();
+    let max_leaf_size = options.max_leaf_size.max(1);
+
+    if num_primitives == 1 {
+        return create_leaf(bbox, primitives);
+    }
+
+    let split_idx = match options.split_method {
+        BvhSplitMethod::Median => {
+            if num_primitives <= max_leaf_size {
+                return create_leaf(bbox, primitives);
+            }
+
+            primitives.sort_by(|p1, p2| p1.center[axis].partial_cmp(&p2.center[axis]).unwrap());
+
+            num_primitives / 2
+        },
+        BvhSplitMethod::Sah => match find_sah_split(primitives, &bbox, options.num_bins.max(2)) {
+            Some((split_axis, split_bin, split_cost)) => {
+                let leaf_cost = INTERSECTION_COST * num_primitives as f32;
+
+                if num_primitives <= max_leaf_size && leaf_cost <= split_cost {
+                    return create_leaf(bbox, primitives);
+                }
+
+                let centers_bbox = compute_centers_bbox(primitives);
+                let split_idx = partition(primitives, |p| {
+                    compute_bin_idx(p.center[split_axis], &centers_bbox, split_axis, options.num_bins.max(2)) <= split_bin
+                });
+
+                // Should not happen, but float rounding may put everything on one side
+                if split_idx == 0 || split_idx == num_primitives { num_primitives / 2 } else { split_idx }
+            },
+            // All the centers coincide, so the primitives can only be split arbitrarily
+            None if num_primitives <= max_leaf_size => return create_leaf(bbox, primitives),
+            None => num_primitives / 2,
+        },
+    };
+
+    let (primitives_left, primitives_right) = primitives.split_at_mut(split_idx);
+    let node_left = build_node(primitives_left, (axis + 1) % 3, options);
+    let node_right = build_node(primitives_right, (axis + 1) % 3, options);
+
+    BvhBuildNode {
+        bbox: bbox,
+        primitives: vec![],
+        children: Some(Box::new((node_left, node_right))),
+    }
+}
+
+
+fn create_leaf(bbox: AxisAlignedBox, primitives: &[BuildPrimitive]) -> BvhBuildNode {
+    BvhBuildNode {
+        bbox: bbox,
+        primitives: primitives.iter().map(|p| p.idx).collect(),
+        children: None,
+    }
+}
+
+
+fn compute_centers_bbox(primitives: &[BuildPrimitive]) -> AxisAlignedBox {
+    primitives.iter().fold(AxisAlignedBox::empty(), |bbox, p| bbox.grow(&p.center))
+}
+
+
+fn compute_bin_idx(value: f32, centers_bbox: &AxisAlignedBox, axis: usize, num_bins: usize) -> usize {
+    let relative_pos = (value - centers_bbox.min_corner[axis]) / centers_bbox.compute_extent(axis);
+
+    ((relative_pos * num_bins as f32) as usize).min(num_bins - 1)
+}
+
+
+// Returns the axis, the last bin of the left part and the cost of the best split
+fn find_sah_split(primitives: &[BuildPrimitive], bbox: &AxisAlignedBox, num_bins: usize) -> Option<(usize, usize, f32)> {
+    let centers_bbox = compute_centers_bbox(primitives);
+    let node_area = bbox.compute_surface_area().max(f32::MIN_POSITIVE);
+    let mut best_split = None;
+    let mut best_cost = f32::INFINITY;
+
+    for axis in 0..3 {
+        if centers_bbox.compute_extent(axis) <= 0.0 {
+            continue;
+        }
+
+        let mut bin_bboxes = vec![AxisAlignedBox::empty(); num_bins];
+        let mut bin_counts = vec![0; num_bins];
+
+        for p in primitives.iter() {
+            let bin_idx = compute_bin_idx(p.center[axis], &centers_bbox, axis, num_bins);
+            bin_bboxes[bin_idx] = bin_bboxes[bin_idx].union(&p.bbox);
+            bin_counts[bin_idx] += 1;
+        }
+
+        // Sweeping from the right to know the area and the count of each right part
+        let mut right_areas = vec![0.0; num_bins];
+        let mut right_counts = vec![0; num_bins];
+        let mut right_bbox = AxisAlignedBox::empty();
+        let mut right_count = 0;
+
+        for bin_idx in (1..num_bins).rev() {
+            right_bbox = right_bbox.union(&bin_bboxes[bin_idx]);
+            right_count += bin_counts[bin_idx];
+            right_areas[bin_idx] = right_bbox.compute_surface_area();
+            right_counts[bin_idx] = right_count;
+        }
+
+        let mut left_bbox = AxisAlignedBox::empty();
+        let mut left_count = 0;
+
+        for bin_idx in 0..(num_bins - 1) {
+            left_bbox = left_bbox.union(&bin_bboxes[bin_idx]);
+            left_count += bin_counts[bin_idx];
+
+            if left_count == 0 || right_counts[bin_idx + 1] == 0 {
+                continue;
+            }
+
+            let cost = TRAVERSAL_COST + INTERSECTION_COST * (
+                left_bbox.compute_surface_area() * left_count as f32 +
+                right_areas[bin_idx + 1] * right_counts[bin_idx + 1] as f32
+            ) / node_area;
+
+            if cost < best_cost {
+                best_cost = cost;
+                best_split = Some((axis, bin_idx, cost));
+            }
+        }
+    }
+
+    best_split
+}
+
+
+// Moves the primitives satisfying the predicate to the front and returns their count
+fn partition<F>(primitives: &mut [BuildPrimitive], predicate: F) -> usize where F: Fn(&BuildPrimitive) -> bool {
+    let mut split_idx = 0;
+
+    for i in 0..primitives.len() {
+        if predicate(&primitives[i]) {
+            primitives.swap(i, split_idx);
+            split_idx += 1;
+        }
+    }
+
+    split_idx
+}
+
+
+impl BvhBuildNode {
+    pub fn compute_stats(&self) -> BvhStats {
+        let mut stats = BvhStats {
+            num_primitives: 0,
+            num_nodes: 0,
+            num_leaves: 0,
+            max_depth: 0,
+            max_leaf_size: 0,
+            sah_cost: 0.0,
+        };
+        let root_area = self.bbox.compute_surface_area().max(f32::MIN_POSITIVE);
+
+        self.collect_stats(&mut stats, 0, root_area);
+
+        stats
+    }
+
+    fn collect_stats(&self, stats: &mut BvhStats, depth: usize, root_area: f32) {
+        let relative_area = self.bbox.compute_surface_area() / root_area;
+
+        stats.num_nodes += 1;
+        stats.max_depth = stats.max_depth.max(depth);
+
+        match &self.children {
+            Some(children) => {
+                stats.sah_cost += TRAVERSAL_COST * relative_area;
+                children.0.collect_stats(stats, depth + 1, root_area);
+                children.1.collect_stats(stats, depth + 1, root_area);
+            },
+            None => {
+                stats.sah_cost += INTERSECTION_COST * relative_area * self.primitives.len() as f32;
+                stats.num_leaves += 1;
+                stats.num_primitives += self.primitives.len();
+                stats.max_leaf_size = stats.max_leaf_size.max(self.primitives.len());
+            }
+        }
+    }
+}
+
+
+impl Bvh {
+    // Also returns the order in which the primitives should be stored for the leaf ranges to be valid
+    pub fn from_build_node(root: &BvhBuildNode) -> (Bvh, Vec<usize>) {
+        let mut bvh = Bvh {nodes: vec![]};
+        let mut primitives_order = vec![];
+
+        bvh.flatten_node(root, 0, &mut primitives_order);
+
+        (bvh, primitives_order)
+    }
+
+    fn flatten_node(&mut self, node: &BvhBuildNode, level: i32, primitives_order: &mut Vec<usize>) {
+        // Slightly enlarging the box to not miss the primitives which lie on its sides
+        let bbox = AxisAlignedBox {
+            min_corner: &node.bbox.min_corner + (-0.00001),
+            max_corner: &node.bbox.max_corner + 0.00001,
+        };
+        let node_idx = self.nodes.len();
+
+        self.nodes.push(BvhNode {
+            sphere_center: bbox.compute_center(),
+            sphere_radius: (&bbox.max_corner - &bbox.min_corner).norm() * 0.5 + 0.001,
+            bbox: bbox,
+            offset: primitives_order.len(),
+            num_primitives: node.primitives.len(),
+            level: level,
+        });
+
+        match &node.children {
+            Some(children) => {
+                self.flatten_node(&children.0, level + 1, primitives_order);
+                self.nodes[node_idx].offset = self.nodes.len();
+                self.flatten_node(&children.1, level + 1, primitives_order);
+            },
+            None => primitives_order.extend_from_slice(&node.primitives),
+        }
+    }
+
+    // Finds the closest hit among the primitives and the index of the hit primitive, which is `None`
+    // for a displayed bounding volume. Indices are in the order returned by `from_build_node`,
+    // `compute_primitive_hit` is called with them as well.
+    // Children are visited from front to back and the ones farther than the closest hit are skipped
+    pub fn compute_hit<F>(&self, ray: &Ray, ray_options: RayOptions, mut compute_primitive_hit: F) -> Option<(Option<usize>, Hit)>
+        where F: FnMut(usize) -> Option<Hit> {
+
+        let inv_direction = Vec3::new(1.0 / ray.direction.x, 1.0 / ray.direction.y, 1.0 / ray.direction.z);
+        let mut closest_hit: Option<(Option<usize>, Hit)> = None;
+        let mut t_max = f32::INFINITY;
+        let mut stack = Vec::with_capacity(64);
+
+        if let Some(t_entry) = self.compute_entry_t(0, ray, &inv_direction, t_max, ray_options) {
+            stack.push((0, t_entry));
+        }
+
+        while let Some((node_idx, t_entry)) = stack.pop() {
+            if t_entry >= t_max {
+                continue;
+            }
+
+            let node = &self.nodes[node_idx];
+
+            if node.level >= ray_options.bvh_display_level {
+                // Displaying the bounding volume itself, boxes are not shaded
+                let normal = if ray_options.bv_type == BVType::Sphere {
+                    (&ray.compute_point(t_entry) - &node.sphere_center).normalize()
+                } else {
+                    Vec3::new(0.0, 1.0, 0.0)
+                };
+
+                t_max = t_entry;
+                closest_hit = Some((None, Hit::new(t_entry, normal)));
+                continue;
+            }
+
+            if node.num_primitives > 0 {
+                for primitive_idx in node.offset..(node.offset + node.num_primitives) {
+                    if let Some(hit) = compute_primitive_hit(primitive_idx) {
+                        if hit.t < t_max {
+                            t_max = hit.t;
+                            closest_hit = Some((Some(primitive_idx), hit));
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            let t_left = self.compute_entry_t(node_idx + 1, ray, &inv_direction, t_max, ray_options);
+            let t_right = self.compute_entry_t(node.offset, ray, &inv_direction, t_max, ray_options);
+
+            // The nearer child is pushed last, so it is popped first
+            match (t_left, t_right) {
+                (Some(t_left), Some(t_right)) if t_left <= t_right => {
+                    stack.push((node.offset, t_right));
+                    stack.push((node_idx + 1, t_left));
+                },
+                (Some(t_left), Some(t_right)) => {
+                    stack.push((node_idx + 1, t_left));
+                    stack.push((node.offset, t_right));
+                },
+                (Some(t_left), None) => stack.push((node_idx + 1, t_left)),
+                (None, Some(t_right)) => stack.push((node.offset, t_right)),
+                (None, None) => {},
+            }
+        }
+
+        closest_hit
+    }
+
+    // Any-hit query: stops at the first primitive for which `is_primitive_occluded` is true. Displayed
+    // bounding volumes occlude as well, so the shadows are consistent with what is rendered
+    pub fn is_occluded<F>(&self, ray: &Ray, ray_options: RayOptions, t_max: f32, mut is_primitive_occluded: F) -> bool
+        where F: FnMut(usize) -> bool {
+
+        let inv_direction = Vec3::new(1.0 / ray.direction.x, 1.0 / ray.direction.y, 1.0 / ray.direction.z);
+        let mut stack = Vec::with_capacity(64);
+
+        if self.compute_entry_t(0, ray, &inv_direction, t_max, ray_options).is_some() {
+            stack.push(0);
+        }
+
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+
+            if node.level >= ray_options.bvh_display_level {
+                return true;
+            }
+
+            if node.num_primitives > 0 {
+                if (node.offset..(node.offset + node.num_primitives)).any(|idx| is_primitive_occluded(idx)) {
+                    return true;
+                }
+
+                continue;
+            }
+
+            for child_idx in &[node_idx + 1, node.offset] {
+                if self.compute_entry_t(*child_idx, ray, &inv_direction, t_max, ray_options).is_some() {
+                    stack.push(*child_idx);
+                }
+            }
+        }
+
+        false
+    }
+
+    fn compute_entry_t(&self, node_idx: usize, ray: &Ray, inv_direction: &Vec3, t_max: f32, ray_options: RayOptions) -> Option<f32> {
+        let node = &self.nodes[node_idx];
+
+        if ray_options.bv_type == BVType::Sphere {
+            compute_sphere_entry_t(&node.sphere_center, node.sphere_radius, ray, t_max)
+        } else {
+            node.bbox.compute_entry_t(ray, inv_direction, t_max)
+        }
+    }
+}
+
+
+fn compute_sphere_entry_t(center: &Point, radius: f32, ray: &Ray, t_max: f32) -> Option<f32> {
+    let oc = &ray.origin - center;
+    let a = ray.direction.dot_product(&ray.direction);
+    let b = oc.dot_product(&ray.direction);
+    let c = oc.dot_product(&oc) - radius * radius;
+    let discriminant = b * b - a * c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let t_near = (-b - discriminant.sqrt()) / a;
+    let t_far = (-b + discriminant.sqrt()) / a;
+
+    if t_far < MIN_RAY_T || t_near >= t_max {
+        None
+    } else {
+        Some(t_near.max(0.0))
+    }
+}
+
+
+impl fmt::Display for BvhStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Primitives: {}", self.num_primitives)?;
+        writeln!(f, "Nodes: {} ({} leaves)", self.num_nodes, self.num_leaves)?;
+        writeln!(f, "Max depth: {}", self.max_depth)?;
+        writeln!(f, "Leaf size: {:.2} on average, {} at most", self.num_primitives as f32 / self.num_leaves as f32, self.max_leaf_size)?;
+        write!(f, "SAH cost: {:.3}", self.sah_cost)
+    }
+}
+
+
+#[cfg(test)]
+mod bvh_tests {
+    use super::*;
+
+    fn create_row_of_boxes(num_boxes: usize) -> Vec<AxisAlignedBox> {
+        (0..num_boxes).map(|i| AxisAlignedBox {
+            min_corner: Point::new(i as f32 * 2.0, 0.0, 0.0),
+            max_corner: Point::new(i as f32 * 2.0 + 1.0, 1.0, 1.0),
+        }).collect()
+    }
+
+    #[test]
+    fn test_all_primitives_are_in_leaves() {
+        for split_method in &[BvhSplitMethod::Median, BvhSplitMethod::Sah] {
+            let options = BvhBuildOptions {split_method: *split_method, max_leaf_size: 2, num_bins: 8};
+            let stats = build_bvh(&create_row_of_boxes(37), &options).compute_stats();
+
+            assert_eq!(stats.num_primitives, 37);
+            assert_eq!(stats.num_nodes, stats.num_leaves * 2 - 1);
+            assert!(stats.max_leaf_size <= 2);
+        }
+    }
+
+    #[test]
+    fn test_sah_is_not_worse_than_median() {
+        let mut bboxes = create_row_of_boxes(64);
+        // A far away outlier makes the median split put a huge box on one side
+        bboxes.push(AxisAlignedBox {
+            min_corner: Point::new(1000.0, 0.0, 0.0),
+            max_corner: Point::new(1001.0, 1.0, 1.0),
+        });
+
+        let median_options = BvhBuildOptions {split_method: BvhSplitMethod::Median, ..BvhBuildOptions::defaults()};
+        let median_stats = build_bvh(&bboxes, &median_options).compute_stats();
+        let sah_stats = build_bvh(&bboxes, &BvhBuildOptions::defaults()).compute_stats();
+
+        assert!(sah_stats.sah_cost <= median_stats.sah_cost);
+    }
+
+    #[test]
+    fn test_flattened_traversal() {
+        let bboxes = create_row_of_boxes(20);
+        let (bvh, primitives_order) = Bvh::from_build_node(&build_bvh(&bboxes, &BvhBuildOptions::defaults()));
+        let ray = Ray {
+            origin: Point::new(-1.0, 0.5, 0.5),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        };
+        let mut num_visited = 0;
+
+        let (_, hit) = bvh.compute_hit(&ray, RayOptions::from_depth(0), |primitive_idx| {
+            num_visited += 1;
+            let bbox = &bboxes[primitives_order[primitive_idx]];
+
+            bbox.compute_entry_t(&ray, &Vec3::new(1.0, f32::INFINITY, f32::INFINITY), f32::INFINITY)
+                .map(|t| Hit::new(t, Vec3::new(-1.0, 0.0, 0.0)))
+        }).unwrap();
+
+        assert_eq!(primitives_order.len(), 20);
+        assert!(approx_eq!(f32, hit.t, 1.0, epsilon = 0.001));
+        // The far boxes should be skipped
+        assert!(num_visited < 8);
+    }
+
+    #[test]
+    fn test_displayed_volume_has_no_primitive() {
+        let bboxes = create_row_of_boxes(20);
+        let (bvh, _) = Bvh::from_build_node(&build_bvh(&bboxes, &BvhBuildOptions::defaults()));
+        let ray = Ray {
+            origin: Point::new(-1.0, 0.5, 0.5),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        };
+        let ray_options = RayOptions {bvh_display_level: 1, ..RayOptions::from_depth(0)};
+
+        let (primitive_idx, _) = bvh.compute_hit(&ray, ray_options, |_| None).unwrap();
+
+        assert_eq!(primitive_idx, None);
+    }
+
+    #[test]
+    fn test_any_hit_traversal() {
+        let bboxes = create_row_of_boxes(20);
+        let (bvh, primitives_order) = Bvh::from_build_node(&build_bvh(&bboxes, &BvhBuildOptions::defaults()));
+        let ray = Ray {
+            origin: Point::new(-1.0, 0.5, 0.5),
+            direction: Vec3::new(1.0, 0.0, 0.0),
+            time: 0.0,
+        };
+        let inv_direction = Vec3::new(1.0, f32::INFINITY, f32::INFINITY);
+        let is_occluded = |t_max: f32| bvh.is_occluded(&ray, RayOptions::from_depth(0), t_max, |primitive_idx| {
+            bboxes[primitives_order[primitive_idx]].compute_entry_t(&ray, &inv_direction, t_max).is_some()
+        });
+
+        assert!(!is_occluded(0.9));
+        assert!(is_occluded(1.1));
+        assert!(is_occluded(f32::INFINITY));
+    }
+
+    #[test]
+    fn test_identical_primitives() {
+        let bboxes = vec![create_row_of_boxes(1)[0].clone(); 10];
+        let stats = build_bvh(&bboxes, &BvhBuildOptions::defaults()).compute_stats();
+
+        assert_eq!(stats.num_primitives, 10);
+        assert!(stats.max_leaf_size <= 4);
+    }
+}
@@ -1,14 +1,17 @@
 use std::sync::Arc;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::Path;
 
-use tobj::Model;
+use tobj::{Model, Material};
 
 use crate::surface::surface::{Surface, Hit, VisualData};
-use crate::surface::quadrics::Sphere;
 use crate::surface::aabb::AxisAlignedBox;
+use crate::surface::bvh::{Bvh, BvhBuildOptions, BvhStats, build_bvh};
 use crate::basics::*;
 use crate::surface::MIN_RAY_T;
+use crate::texture::{Texture, WrapMode};
+use crate::error::RtrsError;
 
 // #[derive(Debug, Clone)]
 // pub struct Vertex {
@@ -24,6 +27,8 @@ pub struct Triangle {
     positions: Arc<Vec<Point>>,
     calculated_normals: Arc<Vec<Vec3>>,
     normals: Arc<Vec<Vec3>>,
+    texcoords: Arc<Vec<(f32, f32)>>,
+    material_idx: Option<usize>,
     vis: VisualData,
 }
 
@@ -39,32 +44,18 @@ impl Triangle {
         edge_01.cross_product(&edge_02).normalize()
     }
 
-    pub fn compute_center(&self) -> Point {
-        (&(Vec3::from(&self.positions[self.indices.0])
-        + Vec3::from(&self.positions[self.indices.1])
-        + Vec3::from(&self.positions[self.indices.0])) * (1.0 / 3.0)).into()
+    pub fn compute_bbox(&self) -> AxisAlignedBox {
+        AxisAlignedBox::empty()
+            .grow(&self.positions[self.indices.0])
+            .grow(&self.positions[self.indices.1])
+            .grow(&self.positions[self.indices.2])
     }
 
-    pub fn min_dim(&self, idx: usize) -> f32 {
-        self.positions[self.indices.0][idx]
-            .min(self.positions[self.indices.1][idx])
-            .min(self.positions[self.indices.2][idx])
-    }
-
-    pub fn max_dim(&self, idx: usize) -> f32 {
-        self.positions[self.indices.0][idx]
-            .max(self.positions[self.indices.1][idx])
-            .max(self.positions[self.indices.2][idx])
-    }
-}
-
-
-impl Surface for Triangle {
-    fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
+    // Only finds the ray parameter of the hit, normals are not interpolated
+    fn compute_t(&self, ray: &Ray, face_normal: &Vec3) -> Option<f32> {
         let v0 = &self.positions[self.indices.0];
         let v1 = &self.positions[self.indices.1];
         let v2 = &self.positions[self.indices.2];
-        let face_normal = &self.compute_normal();
         let t_denom = face_normal.dot_product(&ray.direction);
 
         if t_denom.abs() < 0.000001 {
@@ -88,32 +79,70 @@ impl Surface for Triangle {
             return None;
         }
 
-        let normal;
+        Some(t)
+    }
+
+    // Weights of the vertices for a point inside the triangle
+    fn compute_bar_coords(&self, point: &Point) -> (f32, f32, f32) {
+        let v0 = &self.positions[self.indices.0];
+        let v1 = &self.positions[self.indices.1];
+        let v2 = &self.positions[self.indices.2];
+        let area_v0 = (v1 - v0).cross_product(&(point - v0)).norm() / 2.0;
+        let area_v1 = (v2 - v1).cross_product(&(point - v1)).norm() / 2.0;
+        let area_v2 = (v0 - v2).cross_product(&(point - v2)).norm() / 2.0;
+        let area = area_v0 + area_v1 + area_v2;
+
+        // Each vertex is weighted by the area of the opposite subtriangle
+        (area_v1 / area, area_v2 / area, area_v0 / area)
+    }
+}
+
+
+impl Surface for Triangle {
+    fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
+        let face_normal = &self.compute_normal();
+        let t = self.compute_t(ray, face_normal)?;
+        let is_face_normal = ray_options.mesh_normal_type == MeshNormalType::Face;
 
-        if ray_options.mesh_normal_type != MeshNormalType::Face {
-            let area_v0 = (v1 - v0).cross_product(&(hit_point - v0)).norm() / 2.0;
-            let area_v1 = (v2 - v1).cross_product(&(hit_point - v1)).norm() / 2.0;
-            let area_v2 = (v0 - v2).cross_product(&(hit_point - v2)).norm() / 2.0;
-            let area = area_v0 + area_v1 + area_v2;
-            let bar_coords = (area_v0 / area, area_v1 / area, area_v2 / area);
+        if is_face_normal && self.texcoords.is_empty() {
+            return Some(Hit {material_idx: self.material_idx, ..Hit::new(t, face_normal.clone())});
+        }
 
-            normal = (if ray_options.mesh_normal_type == MeshNormalType::Provided && self.normals.len() > 0 {
-                &self.normals[self.indices.0] * bar_coords.1 +
-                &self.normals[self.indices.1] * bar_coords.2 +
-                &self.normals[self.indices.2] * bar_coords.0
+        let bar_coords = self.compute_bar_coords(&ray.compute_point(t));
+        let normal = if is_face_normal {
+            face_normal.clone()
+        } else {
+            (if ray_options.mesh_normal_type == MeshNormalType::Provided && self.normals.len() > 0 {
+                &self.normals[self.indices.0] * bar_coords.0 +
+                &self.normals[self.indices.1] * bar_coords.1 +
+                &self.normals[self.indices.2] * bar_coords.2
             } else {
-                &self.calculated_normals[self.indices.0] * bar_coords.1 +
-                &self.calculated_normals[self.indices.1] * bar_coords.2 +
-                &self.calculated_normals[self.indices.2] * bar_coords.0
-            }).normalize();
+                &self.calculated_normals[self.indices.0] * bar_coords.0 +
+                &self.calculated_normals[self.indices.1] * bar_coords.1 +
+                &self.calculated_normals[self.indices.2] * bar_coords.2
+            }).normalize()
+        };
+        let uv = if self.texcoords.is_empty() {
+            (0.0, 0.0)
         } else {
-            normal = face_normal.clone();
-        }
+            let (uv0, uv1, uv2) = (self.texcoords[self.indices.0], self.texcoords[self.indices.1], self.texcoords[self.indices.2]);
+
+            (
+                uv0.0 * bar_coords.0 + uv1.0 * bar_coords.1 + uv2.0 * bar_coords.2,
+                uv0.1 * bar_coords.0 + uv1.1 * bar_coords.1 + uv2.1 * bar_coords.2,
+            )
+        };
 
-        Some(Hit {t, normal})
+        Some(Hit {t, normal, uv, material_idx: self.material_idx})
+    }
+
+    fn is_occluded(&self, ray: &Ray, _ray_options: RayOptions, t_max: f32) -> bool {
+        self.compute_t(ray, &self.compute_normal()).map_or(false, |t| t < t_max)
     }
 
     fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> { Some(self.compute_bbox()) }
 }
 
 #[derive(Debug, Clone)]
@@ -123,17 +152,27 @@ pub struct TriangleMesh {
     positions: Arc<Vec<Point>>,
     calculated_normals: Arc<Vec<Vec3>>,
     normals: Arc<Vec<Vec3>>,
-    bvh: Option<BoundingVolumeHierarchy>,
+    bvh: Option<Bvh>,
     pub vis: VisualData,
+    // Materials of the .mtl file, which are used instead of `vis` if `use_materials` is set
+    pub materials: Vec<VisualData>,
+    pub use_materials: bool,
 }
 
 
 // impl TriangleMesh {
 impl TriangleMesh {
-    pub fn from_obj(obj_file: &str, vis: VisualData) -> Self {
-        let (models, _) = tobj::load_obj(&obj_file, true).unwrap();
+    pub fn from_obj(obj_file: &str, vis: VisualData) -> Result<Self, RtrsError> {
+        let obj_path = Path::new(obj_file);
+        let (models, obj_materials) = load_obj(obj_path)?;
+        let base_dir = obj_path.parent().unwrap_or(Path::new(""));
+        let materials = obj_materials.iter()
+            .map(|material| visual_data_from_material(material, base_dir, &vis))
+            .collect::<Vec<VisualData>>();
         let mut positions = vec![];
         let mut normals = vec![];
+        let mut texcoords = vec![];
+        let has_texcoords = models.iter().any(|model| model.mesh.texcoords.len() > 0);
 
         // We are going to convert a flattened array of [x1, y1, z1, x2, y2, z2, ...]
         // into an array of points [(x1, y1, z1), (x2, y2, z2), ...]
@@ -154,11 +193,21 @@ impl TriangleMesh {
                         model.mesh.normals[i * 3 + 2]
                     ));
                 }
+
+                // Keeping the texcoords aligned with the positions even if some models lack them
+                if has_texcoords {
+                    texcoords.push(if model.mesh.texcoords.len() > 0 {
+                        (model.mesh.texcoords[i * 2 + 0], model.mesh.texcoords[i * 2 + 1])
+                    } else {
+                        (0.0, 0.0)
+                    });
+                }
             }
         }
 
         let positions_arc = Arc::new(positions);
         let normals_arc = Arc::new(normals);
+        let texcoords_arc = Arc::new(texcoords);
         let mut triangles = vec![];
         let mut index_shift: usize = 0;
 
@@ -176,6 +225,8 @@ impl TriangleMesh {
                     positions: positions_arc.clone(),
                     calculated_normals: Arc::new(vec![]),
                     normals: normals_arc.clone(),
+                    texcoords: texcoords_arc.clone(),
+                    material_idx: model.mesh.material_id.filter(|idx| *idx < materials.len()),
                     vis: vis.clone(),
                 });
             }
@@ -183,6 +234,10 @@ impl TriangleMesh {
             index_shift += model.mesh.positions.len() / 3;
         }
 
+        if triangles.is_empty() {
+            return Err(RtrsError::EmptyMesh(obj_path.to_path_buf()));
+        }
+
         // We are going fill it the following way
         // We iterate over each triangle and each triangle
         let mut all_calculated_normals = vec![vec![]; positions_arc.len()];
@@ -201,14 +256,32 @@ impl TriangleMesh {
             triangles[triangle_idx].calculated_normals = calculated_normals_arc.clone();
         }
 
-        TriangleMesh {
-            bvh: Some(BoundingVolumeHierarchy::from_triangles_list(triangles.clone(), 0, 0)),
+        let mut mesh = TriangleMesh {
+            bvh: None,
             positions: positions_arc,
             calculated_normals: calculated_normals_arc,
             triangles: triangles,
             normals: normals_arc,
             vis: vis,
-        }
+            materials: materials,
+            use_materials: false,
+        };
+        mesh.build_bvh(&BvhBuildOptions::defaults());
+
+        Ok(mesh)
+    }
+
+    // Rebuilds the hierarchy with the given builder and returns its statistics
+    pub fn build_bvh(&mut self, options: &BvhBuildOptions) -> BvhStats {
+        let bboxes = self.triangles.iter().map(|t| t.compute_bbox()).collect::<Vec<AxisAlignedBox>>();
+        let build_root = build_bvh(&bboxes, options);
+        let (bvh, triangles_order) = Bvh::from_build_node(&build_root);
+
+        // Storing the triangles in the leaves order, so each leaf refers to a range of them
+        self.triangles = triangles_order.iter().map(|idx| self.triangles[*idx].clone()).collect();
+        self.bvh = Some(bvh);
+
+        build_root.compute_stats()
     }
 
     fn compute_slow_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
@@ -233,174 +306,87 @@ impl TriangleMesh {
 impl Surface for TriangleMesh {
     fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
         if self.bvh.is_some() && ray_options.bv_type != BVType::None {
-            self.bvh.as_ref().unwrap().compute_hit(ray, ray_options)
+            self.bvh.as_ref().unwrap()
+                .compute_hit(ray, ray_options, |idx| self.triangles[idx].compute_hit(ray, ray_options))
+                .map(|(_, hit)| hit)
         } else {
             self.compute_slow_hit(ray, ray_options)
         }
     }
 
-    fn get_visual_data(&self) -> VisualData { self.vis.clone() }
-}
-
-#[derive(Debug, Clone)]
-struct BoundingVolumeHierarchy {
-    triangle_left: Option<Triangle>,
-    triangle_right: Option<Triangle>,
-    bvh_left: Option<Box<BoundingVolumeHierarchy>>,
-    bvh_right: Option<Box<BoundingVolumeHierarchy>>,
-    sphere: Sphere,
-    bbox: AxisAlignedBox,
-    vis: VisualData,
-    bvh_level: i32,
-}
-
-
-impl BoundingVolumeHierarchy {
-    pub fn from_triangles_list(triangles: Vec<Triangle>, bvh_level: i32, axis: u32) -> Self {
-        assert!(triangles.len() > 0);
-
-        if triangles.len() == 1 {
-            return BoundingVolumeHierarchy {
-                sphere: BoundingVolumeHierarchy::compute_sphere_from_triangles(&triangles),
-                bbox: BoundingVolumeHierarchy::compute_bbox_from_triangles(&triangles),
-                triangle_left: Some(triangles[0].clone()),
-                triangle_right: None,
-                bvh_left: None,
-                bvh_right: None,
-                vis: triangles[0].vis.clone(),
-                bvh_level: bvh_level,
-            };
-        }
-
-        if triangles.len() == 2 {
-            return BoundingVolumeHierarchy {
-                sphere: BoundingVolumeHierarchy::compute_sphere_from_triangles(&triangles),
-                bbox: BoundingVolumeHierarchy::compute_bbox_from_triangles(&triangles),
-                triangle_left: Some(triangles[0].clone()),
-                triangle_right: Some(triangles[1].clone()),
-                bvh_left: None,
-                bvh_right: None,
-                vis: triangles[0].vis.clone(),
-                bvh_level: bvh_level + 1,
-            };
-        }
-
-        let sphere = BoundingVolumeHierarchy::compute_sphere_from_triangles(&triangles);
-        let bbox = BoundingVolumeHierarchy::compute_bbox_from_triangles(&triangles);
-        let mut triangles = triangles;
-        if axis == 0 {
-            triangles.sort_by(|t1, t2| t1.compute_center().x.partial_cmp(&t2.compute_center().x).unwrap());
-        } else if axis == 1 {
-            triangles.sort_by(|t1, t2| t1.compute_center().y.partial_cmp(&t2.compute_center().y).unwrap());
-        } else if axis == 2 {
-            triangles.sort_by(|t1, t2| t1.compute_center().y.partial_cmp(&t2.compute_center().y).unwrap());
+    fn is_occluded(&self, ray: &Ray, ray_options: RayOptions, t_max: f32) -> bool {
+        if self.bvh.is_some() && ray_options.bv_type != BVType::None {
+            self.bvh.as_ref().unwrap()
+                .is_occluded(ray, ray_options, t_max, |idx| self.triangles[idx].is_occluded(ray, ray_options, t_max))
         } else {
-            panic!("Wrong axis: {}", axis);
-        }
-        let (triangles_left, triangles_right) = triangles.split_at(triangles.len() / 2);
-
-        let triangle_left = if triangles_left.len() == 1 {Some(triangles_left[0].clone())} else {None};
-        let bvh_left = if triangles_left.len() == 1 {None} else {
-            Some(Box::new(BoundingVolumeHierarchy::from_triangles_list(triangles_left.to_vec(), bvh_level + 1, (axis + 1) % 3)))
-        };
-        let triangle_right = if triangles_right.len() == 1 {Some(triangles_right[0].clone())} else {None};
-        let bvh_right = if triangles_right.len() == 1 {None} else {
-            Some(Box::new(BoundingVolumeHierarchy::from_triangles_list(triangles_right.to_vec(), bvh_level + 1, (axis + 1) % 3)))
-        };
-
-        BoundingVolumeHierarchy  {
-            vis: triangles_left[0].vis.clone(),
-            triangle_left: triangle_left,
-            triangle_right: triangle_right,
-            bvh_left: bvh_left,
-            bvh_right: bvh_right,
-            sphere: sphere,
-            bbox: bbox,
-            bvh_level: bvh_level
+            self.triangles.iter().any(|triangle| triangle.is_occluded(ray, ray_options, t_max))
         }
     }
 
-    pub fn compute_sphere_from_triangles(triangles: &Vec<Triangle>) -> Sphere {
-        let mut min = &Point::zero() + f32::INFINITY;
-        let mut max = &Point::zero() + (-f32::INFINITY);
-
-        for i in 0..triangles.len() {
-            min.x = min.x.min(triangles[i].min_dim(0));
-            min.y = min.y.min(triangles[i].min_dim(1));
-            min.z = min.z.min(triangles[i].min_dim(2));
-            max.x = max.x.max(triangles[i].max_dim(0));
-            max.y = max.y.max(triangles[i].max_dim(1));
-            max.z = max.z.max(triangles[i].max_dim(2));
-        }
-        let center = &min + &(&(&max - &min) * 0.5);
-        let radius = (&max - &min).norm() * 0.5;
-
-        Sphere::from_position(radius + 0.001, center)
-    }
+    fn get_visual_data(&self) -> VisualData { self.vis.clone() }
 
-    pub fn compute_bbox_from_triangles(triangles: &Vec<Triangle>) -> AxisAlignedBox {
-        let mut min = &Point::zero() + f32::INFINITY;
-        let mut max = &Point::zero() + (-f32::INFINITY);
-
-        for i in 0..triangles.len() {
-            min.x = min.x.min(triangles[i].min_dim(0));
-            min.y = min.y.min(triangles[i].min_dim(1));
-            min.z = min.z.min(triangles[i].min_dim(2));
-            max.x = max.x.max(triangles[i].max_dim(0));
-            max.y = max.y.max(triangles[i].max_dim(1));
-            max.z = max.z.max(triangles[i].max_dim(2));
+    fn get_hit_visual_data(&self, hit: &Hit) -> VisualData {
+        match hit.material_idx {
+            Some(material_idx) if self.use_materials => self.materials[material_idx].clone(),
+            _ => self.vis.clone(),
         }
+    }
 
-        AxisAlignedBox {
-            min_corner: &min + (-0.00001),
-            max_corner: &max + 0.00001,
-        }
+    fn bounding_box(&self) -> Option<AxisAlignedBox> {
+        Some(self.triangles.iter().fold(AxisAlignedBox::empty(), |bbox, t| bbox.union(&t.compute_bbox())))
     }
 }
 
+pub fn load_obj(obj_path: &Path) -> Result<(Vec<Model>, Vec<Material>), RtrsError> {
+    // tobj does not tell the reason why a file could not be opened
+    if !obj_path.is_file() {
+        return Err(RtrsError::MissingFile(obj_path.to_path_buf()));
+    }
 
-impl Surface for BoundingVolumeHierarchy {
-    fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
-        let bv_hit = (if ray_options.bv_type == BVType::Sphere {
-            self.sphere.compute_hit(ray, ray_options)
-        } else {
-            self.bbox.compute_hit(ray, ray_options)
-        })?;
+    tobj::load_obj(obj_path, true).map_err(|err| RtrsError::MalformedObj(obj_path.to_path_buf(), err.to_string()))
+}
 
-        if self.bvh_level >= ray_options.bvh_display_level {
-            return Some(bv_hit);
-        }
 
-        let left_hit = if self.triangle_left.is_some() {
-            self.triangle_left.as_ref().unwrap().compute_hit(ray, ray_options)
-        } else if self.bvh_left.is_some() {
-            (*self.bvh_left.as_ref().unwrap()).compute_hit(ray, ray_options)
-        } else {
-            None
-        };
+// Maps an .mtl material onto the visual data, the optional fields which are not specified are taken from `default_vis`.
+// tobj reads a missing `Kd` as black, so a black diffuse color of a textured material is replaced with white
+// to keep the texture visible. Illumination models 0 and 1 have no highlights, models starting from 3 add reflections
+pub fn visual_data_from_material(material: &Material, base_dir: &Path, default_vis: &VisualData) -> VisualData {
+    let mut vis = default_vis.clone();
+    let specular = (material.specular[0] + material.specular[1] + material.specular[2]) / 3.0;
+    let illumination_model = material.illumination_model.unwrap_or(2);
+    let is_black = material.diffuse.iter().all(|c| *c == 0.0);
+
+    vis.color = if is_black && !material.diffuse_texture.is_empty() {
+        Color::new(1.0, 1.0, 1.0)
+    } else {
+        Color::new(material.diffuse[0], material.diffuse[1], material.diffuse[2])
+    };
+    vis.specular_strength = if illumination_model >= 2 { specular } else { 0.0 };
+    vis.reflection_strength = if illumination_model >= 3 { specular } else { 0.0 };
+    vis.transparency = (1.0 - material.dissolve).clamp(0.0, 1.0);
+
+    if material.shininess > 0.0 {
+        vis.shininess = material.shininess;
+    }
 
-        let right_hit = if self.triangle_right.is_some() {
-            self.triangle_right.as_ref().unwrap().compute_hit(ray, ray_options)
-        } else if self.bvh_right.is_some() {
-            (*self.bvh_right.as_ref().unwrap()).compute_hit(ray, ray_options)
-        } else {
-            None
-        };
+    if material.optical_density > 0.0 {
+        vis.refractive_index = material.optical_density;
+    }
 
-        if left_hit.is_some() {
-            if right_hit.is_some() {
-                let left_hit = left_hit.unwrap();
-                let right_hit = right_hit.unwrap();
+    if !material.diffuse_texture.is_empty() {
+        let texture_path = base_dir.join(&material.diffuse_texture);
 
-                Some(if left_hit.t < right_hit.t { left_hit } else { right_hit })
-            } else {
-                left_hit
+        vis.diffuse_texture = match Texture::from_file(&texture_path.to_string_lossy(), WrapMode::Repeat) {
+            Ok(texture) => Some(Arc::new(texture)),
+            Err(err) => {
+                // A missing texture should not prevent the mesh from being rendered
+                eprintln!("Skipping the texture of material {}: {}", material.name, err);
+                None
             }
-        } else {
-            right_hit
-        }
+        };
     }
-    fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    vis
 }
 
 
@@ -429,6 +415,8 @@ mod mesh_tests {
             indices: (0, 1, 2),
             positions: positions.clone(),
             normals: Arc::new(vec![]),
+            texcoords: Arc::new(vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]),
+            material_idx: None,
             calculated_normals: Arc::new(vec![
                 Vec3::new(1.0, 0.0, 0.0),
                 Vec3::new(0.0, 1.0, 0.0),
@@ -443,6 +431,7 @@ mod mesh_tests {
         let ray = Ray {
             origin: Point {x: 0.0, y: 0.0, z: 0.0},
             direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
+            time: 0.0,
         };
         let triangle_a = create_dummy_triangle();
         let hit = triangle_a.compute_hit(&ray, RayOptions::from_depth(0)).unwrap();
@@ -451,13 +440,88 @@ mod mesh_tests {
         println!("{:?}", hit);
     }
 
+    #[test]
+    fn test_triangle_texcoords() {
+        let ray = Ray {
+            origin: Point {x: 0.25, y: 0.5, z: 0.0},
+            direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
+            time: 0.0,
+        };
+        let hit = create_dummy_triangle().compute_hit(&ray, RayOptions::from_depth(0)).unwrap();
+
+        assert!(approx_eq!(f32, hit.uv.0, 0.25, epsilon = 0.0001));
+        assert!(approx_eq!(f32, hit.uv.1, 0.5, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn test_mesh_materials() {
+        let mut mesh = TriangleMesh::from_obj("resources/two_materials.obj", VisualData::grey()).unwrap();
+        let compute_vis = |mesh: &TriangleMesh, x: f32| {
+            let ray = Ray {
+                origin: Point {x: x, y: 0.5, z: 0.0},
+                direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
+                time: 0.0,
+            };
+
+            mesh.get_hit_visual_data(&mesh.compute_hit(&ray, RayOptions::from_depth(0)).unwrap())
+        };
+
+        assert_eq!(mesh.materials.len(), 2);
+        // The materials are only used on request
+        assert_eq!(compute_vis(&mesh, -0.5).color, VisualData::grey().color);
+
+        mesh.use_materials = true;
+        let red = compute_vis(&mesh, -0.5);
+        let glass = compute_vis(&mesh, 0.5);
+
+        assert_eq!(red.color, Color::new(1.0, 0.0, 0.0));
+        assert!(approx_eq!(f32, red.specular_strength, 0.5));
+        assert!(approx_eq!(f32, red.shininess, 32.0));
+        assert_eq!(glass.color, Color::new(0.0, 1.0, 0.0));
+        assert!(approx_eq!(f32, glass.specular_strength, 0.0));
+        assert!(approx_eq!(f32, glass.refractive_index, 1.5));
+        assert!(approx_eq!(f32, glass.transparency, 0.8));
+    }
+
+    #[test]
+    fn test_texture_only_material() {
+        let dir = std::env::temp_dir();
+        let mtl_path = dir.join("rtrs_test_texture_only.mtl");
+        image::RgbImage::from_pixel(1, 1, image::Rgb([255, 0, 0])).save(dir.join("rtrs_test_texture_only.png")).unwrap();
+        std::fs::write(&mtl_path, "newmtl Textured\nmap_Kd rtrs_test_texture_only.png\n").unwrap();
+
+        let (materials, _) = tobj::load_mtl(&mtl_path).unwrap();
+        let vis = visual_data_from_material(&materials[0], &dir, &VisualData::grey());
+
+        // The texture should not be multiplied by the black default of the missing `Kd`
+        assert_eq!(vis.color, Color::new(1.0, 1.0, 1.0));
+        assert!(vis.diffuse_texture.is_some());
+    }
+
+    #[test]
+    fn test_loading_errors() {
+        match TriangleMesh::from_obj("resources/missing.obj", VisualData::grey()) {
+            Err(RtrsError::MissingFile(_)) => {},
+            result => panic!("Expected a missing file error, got {:?}", result.map(|_| ())),
+        }
+
+        let path = std::env::temp_dir().join("rtrs_test_points.obj");
+        std::fs::write(&path, "v 0 0 0\nv 1 0 0\nv 0 1 0\n").unwrap();
+
+        match TriangleMesh::from_obj(&path.to_string_lossy(), VisualData::grey()) {
+            Err(RtrsError::EmptyMesh(_)) => {},
+            result => panic!("Expected an empty mesh error, got {:?}", result.map(|_| ())),
+        }
+    }
+
     #[test]
     fn test_ray_mesh_intersection() {
         // let mesh = TriangleMesh::from_obj("resources/square.obj");
-        let mesh = TriangleMesh::from_obj("resources/cube.obj");
+        let mesh = TriangleMesh::from_obj("resources/cube.obj", VisualData::grey()).unwrap();
         let ray = Ray {
             origin: Point {x: 0.0, y: 0.0, z: -1.0},
             direction: Vec3 {x: 0.0, y: 0.0, z: 1.0},
+            time: 0.0,
         };
 
         let t = mesh.compute_hit(&ray, RayOptions::from_depth(0)).unwrap().t;
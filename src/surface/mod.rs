@@ -2,5 +2,6 @@ pub mod surface;
 pub mod quadrics;
 pub mod mesh;
 pub mod aabb;
+pub mod bvh;
 
 pub static MIN_RAY_T: f32 = 0.0001;
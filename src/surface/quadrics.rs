@@ -1,7 +1,9 @@
 use crate::surface::surface::{Surface, Hit, VisualData};
+use crate::surface::aabb::AxisAlignedBox;
 use crate::basics::*;
 use crate::matrix::{Mat3, AffineMat3, DiagMat3};
 use crate::surface::MIN_RAY_T;
+use std::f32::consts::PI;
 
 
 #[derive(Debug, Clone)]
@@ -32,25 +34,42 @@ impl Sphere {
     pub fn compute_normal(&self, point: &Point) -> Vec3 {
         &(point - &self.center) * (1. / self.radius)
     }
-}
-
 
-impl Surface for Sphere {
-    fn compute_hit(&self, ray: &Ray, _ray_options: RayOptions) -> Option<Hit> {
+    fn compute_t(&self, ray: &Ray) -> Option<f32> {
         let orig_to_c = &self.center - &ray.origin;
         let roots = find_square_roots(
             ray.direction.norm_squared(),
             -2.0 * ray.direction.dot_product(&orig_to_c),
             orig_to_c.norm_squared() - self.radius * self.radius,
         )?;
-        let t = select_smallest_positive_root(roots)?;
+
+        select_smallest_positive_root(roots)
+    }
+}
+
+
+impl Surface for Sphere {
+    fn compute_hit(&self, ray: &Ray, _ray_options: RayOptions) -> Option<Hit> {
+        let t = self.compute_t(ray)?;
         let hit_point = ray.compute_point(t);
         let normal = self.compute_normal(&hit_point);
+        let uv = compute_spherical_uv(&normal);
+
+        Some(Hit::with_uv(t, normal, uv))
+    }
 
-        Some(Hit::new(t, normal))
+    fn is_occluded(&self, ray: &Ray, _ray_options: RayOptions, t_max: f32) -> bool {
+        self.compute_t(ray).map_or(false, |t| t < t_max)
     }
 
     fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> {
+        Some(AxisAlignedBox {
+            min_corner: &self.center + (-self.radius),
+            max_corner: &self.center + self.radius,
+        })
+    }
 }
 
 
@@ -76,10 +95,21 @@ impl Plane {
 
 impl Surface for Plane {
     fn compute_hit(&self, ray: &Ray, _ray_options: RayOptions) -> Option<Hit> {
-        compute_plane_hit(&self.bias, &self.normal, ray)
+        let hit = compute_plane_hit(&self.bias, &self.normal, ray)?;
+
+        // Texture is repeated every unit along two orthogonal directions of the plane
+        let helper_axis = if self.normal.x.abs() < 0.9 { Vec3::new(1.0, 0.0, 0.0) } else { Vec3::new(0.0, 1.0, 0.0) };
+        let u_axis = (&helper_axis + &(&self.normal * -helper_axis.dot_product(&self.normal))).normalize();
+        let v_axis = u_axis.cross_product(&self.normal);
+        let offset = &ray.compute_point(hit.t) - &self.bias;
+
+        Some(Hit::with_uv(hit.t, hit.normal, (offset.dot_product(&u_axis), offset.dot_product(&v_axis))))
     }
 
     fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    // The plane is infinite
+    fn bounding_box(&self) -> Option<AxisAlignedBox> { None }
 }
 
 
@@ -99,11 +129,8 @@ impl Ellipsoid {
             z: 2.0 * (point.z - self.center.z) / (self.scale.c * self.scale.c),
         }).normalize()
     }
-}
-
 
-impl Surface for Ellipsoid {
-    fn compute_hit(&self, ray: &Ray, _ray_options: RayOptions) -> Option<Hit> {
+    fn compute_t(&self, ray: &Ray) -> Option<f32> {
         let scale_inv = self.scale.compute_inverse();
         let orig_to_c_scaled = &scale_inv * &(&self.center - &ray.origin);
         let ray_dir_scaled = &scale_inv * &ray.direction;
@@ -113,14 +140,39 @@ impl Surface for Ellipsoid {
             orig_to_c_scaled.norm_squared() - 1.0,
         )?;
 
-        let t = select_smallest_positive_root(roots)?;
+        select_smallest_positive_root(roots)
+    }
+}
+
+
+impl Surface for Ellipsoid {
+    fn compute_hit(&self, ray: &Ray, _ray_options: RayOptions) -> Option<Hit> {
+        let t = self.compute_t(ray)?;
         let hit_point = ray.compute_point(t);
         let normal = self.compute_normal(&hit_point);
+        let uv = compute_spherical_uv(&Vec3::new(
+            (hit_point.x - self.center.x) / self.scale.a,
+            (hit_point.y - self.center.y) / self.scale.b,
+            (hit_point.z - self.center.z) / self.scale.c,
+        ));
+
+        Some(Hit::with_uv(t, normal, uv))
+    }
 
-        Some(Hit {t: t, normal: normal})
+    fn is_occluded(&self, ray: &Ray, _ray_options: RayOptions, t_max: f32) -> bool {
+        self.compute_t(ray).map_or(false, |t| t < t_max)
     }
 
     fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> {
+        let half_size = Vec3::new(self.scale.a.abs(), self.scale.b.abs(), self.scale.c.abs());
+
+        Some(AxisAlignedBox {
+            min_corner: &self.center + &(-&half_size),
+            max_corner: &self.center + &half_size,
+        })
+    }
 }
 
 
@@ -135,6 +187,19 @@ pub struct Cone {
 
 impl Cone {
     fn compute_cone_hit(&self, ray: &Ray) -> Option<Hit> {
+        let t = self.compute_cone_t(ray)?;
+        let hit_point = ray.compute_point(t);
+        let normal = self.compute_normal(&hit_point);
+        // The angle around the axis and the height above the slab
+        let uv = (
+            0.5 + (hit_point.z - self.apex.z).atan2(hit_point.x - self.apex.x) / (2.0 * PI),
+            (hit_point.y - (self.apex.y - self.height)) / self.height,
+        );
+
+        Some(Hit::with_uv(t, normal, uv))
+    }
+
+    fn compute_cone_t(&self, ray: &Ray) -> Option<f32> {
         let s = self.half_angle.tanh().powi(2);
         let roots = find_square_roots(
             ray.direction.x.powi(2) + ray.direction.z.powi(2) - ray.direction.y.powi(2) * s,
@@ -146,13 +211,10 @@ impl Cone {
         let py = ray.origin.y + t * ray.direction.y;
 
         if py <= self.apex.y && py >= (self.apex.y - self.height) {
-            let hit_point = ray.compute_point(t);
-            let normal = self.compute_normal(&hit_point);
-
-            return Some(Hit {t: t, normal: normal});
+            Some(t)
+        } else {
+            None
         }
-
-        None
     }
 
     fn compute_slab_hit(&self, ray: &Ray) -> Option<Hit> {
@@ -163,7 +225,12 @@ impl Cone {
         let hit_point = ray.compute_point(plane_hit.t);
 
         if (&hit_point - &center).norm_squared() < radius.powi(2) {
-            Some(plane_hit)
+            let uv = (
+                0.5 + (hit_point.x - center.x) / (2.0 * radius),
+                0.5 + (hit_point.z - center.z) / (2.0 * radius),
+            );
+
+            Some(Hit::with_uv(plane_hit.t, plane_hit.normal, uv))
         } else {
             None
         }
@@ -207,11 +274,38 @@ impl Surface for Cone {
         }
     }
 
+    fn is_occluded(&self, ray: &Ray, _ray_options: RayOptions, t_max: f32) -> bool {
+        // The slab is only tested if the side surface is not in the way
+        self.compute_cone_t(ray).map_or(false, |t| t < t_max) ||
+            self.compute_slab_hit(ray).map_or(false, |hit| hit.t < t_max)
+    }
+
     fn get_visual_data(&self) -> VisualData { self.vis.clone() }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> {
+        // The widest part is the slab at the bottom
+        let radius = self.height * self.half_angle.tanh();
+
+        Some(AxisAlignedBox {
+            min_corner: Point::new(self.apex.x - radius, self.apex.y - self.height, self.apex.z - radius),
+            max_corner: Point::new(self.apex.x + radius, self.apex.y, self.apex.z + radius),
+        })
+    }
 }
 
 
 
+// Longitude and latitude of a direction from the center of a sphere
+fn compute_spherical_uv(direction: &Vec3) -> (f32, f32) {
+    let direction = direction.normalize();
+
+    (
+        0.5 + direction.z.atan2(direction.x) / (2.0 * PI),
+        0.5 + direction.y.clamp(-1.0, 1.0).asin() / PI,
+    )
+}
+
+
 #[inline]
 fn find_square_roots(a: f32, b: f32, c: f32) -> Option<(f32, Option<f32>)> {
     // Finds roots of a quadratic equation
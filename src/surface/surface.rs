@@ -1,21 +1,30 @@
 use std::marker::Sync;
 use std::fmt::Debug;
+use std::sync::Arc;
 // use std::cmp::Ordering;
 
 use crate::basics::*;
-use crate::matrix::{Mat3, AffineMat3};
+use crate::matrix::{Mat3, AffineMat3, AnimatedTransform};
+use crate::surface::aabb::AxisAlignedBox;
+use crate::texture::Texture;
 
 
 #[derive(Debug, Clone)]
 pub struct Hit {
     pub t: f32,
     pub normal: Vec3,
+    pub uv: (f32, f32), // Texture coordinates, zeros for surfaces without a parametrization
+    pub material_idx: Option<usize>, // For surfaces which consist of parts with different materials
 }
 
 
 impl Hit {
     pub fn new(t: f32, normal: Vec3) -> Self {
-        Hit {t, normal}
+        Hit {t, normal, uv: (0.0, 0.0), material_idx: None}
+    }
+
+    pub fn with_uv(t: f32, normal: Vec3, uv: (f32, f32)) -> Self {
+        Hit {t, normal, uv, material_idx: None}
     }
 
     pub fn inf() -> Self {
@@ -29,6 +38,12 @@ pub struct VisualData {
     pub specular_strength: f32,
     pub reflection_strength: f32,
     pub reflection_glossiness: f32,
+    pub refractive_index: f32,
+    pub transparency: f32,
+    pub absorption: Color,
+    pub shininess: f32, // Phong exponent of the specular highlight
+    // Modulates `color` at the hit texture coordinates
+    pub diffuse_texture: Option<Arc<Texture>>,
 }
 
 
@@ -39,6 +54,11 @@ impl VisualData {
             specular_strength: 0.0,
             reflection_strength: 0.0,
             reflection_glossiness: 0.0,
+            refractive_index: 1.0,
+            transparency: 0.0,
+            absorption: Color::zero(),
+            shininess: 64.0,
+            diffuse_texture: None,
         }
     }
 
@@ -47,7 +67,14 @@ impl VisualData {
     }
 
     pub fn grey() -> Self {
-        VisualData::from_color(&Color {r: 0.74, g: 0.76, b: 0.78})
+        VisualData::from_color(&Color::from_srgb(0.74, 0.76, 0.78))
+    }
+
+    pub fn compute_color(&self, uv: &(f32, f32)) -> Color {
+        match &self.diffuse_texture {
+            Some(texture) => &self.color * &texture.sample(uv.0, uv.1),
+            None => self.color.clone(),
+        }
     }
 }
 
@@ -55,6 +82,18 @@ impl VisualData {
 pub trait Surface: Debug + Sync {
     fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit>;
     fn get_visual_data(&self) -> VisualData;
+
+    // Visual data at the given hit, differs from `get_visual_data` for multi-material surfaces
+    fn get_hit_visual_data(&self, _hit: &Hit) -> VisualData { self.get_visual_data() }
+
+    // Checks if there is any hit closer than `t_max`, which is enough for shadow rays.
+    // Surfaces override it to skip computing normals and the closest hit
+    fn is_occluded(&self, ray: &Ray, ray_options: RayOptions, t_max: f32) -> bool {
+        self.compute_hit(ray, ray_options).map_or(false, |hit| hit.t < t_max)
+    }
+
+    // None means that the surface is either unbounded or its extent is unknown
+    fn bounding_box(&self) -> Option<AxisAlignedBox> { None }
 }
 
 
@@ -63,10 +102,15 @@ pub struct TransformedSurface<S> where S: Surface {
     transformation: AffineMat3,
     transformation_inv: AffineMat3,
     transform_inv_t: Mat3,
+    motion: Option<AnimatedTransform>, // The surface is static if not given
     surface: S,
 }
 
 
+// The number of time steps at which the bounding box of a moving surface is computed
+static NUM_MOTION_BBOX_STEPS: u32 = 16;
+
+
 impl<S: Surface> TransformedSurface<S> {
     pub fn new(transformation: AffineMat3, surface: S) -> TransformedSurface<S> {
         let transformation_inv = transformation.compute_inverse();
@@ -75,36 +119,97 @@ impl<S: Surface> TransformedSurface<S> {
             transformation: transformation,
             transform_inv_t: transformation_inv.transform_mat.transpose(),
             transformation_inv: transformation_inv,
+            motion: None,
             surface: surface,
         }
     }
 
-    fn transform_normal(&self, normal: &Vec3) -> Vec3 {
-        // TODO: just return it in compute_hit
-        // let point_object = &self.transformation_inv * point;
-        // let normal = self.surface.transform_normal(&point_object);
+    // Moves the surface from the current transformation at the shutter opening to `end_transformation`
+    // at its closing. Rays with different times see the surface in different places, which blurs it
+    pub fn with_end_transformation(self, end_transformation: AffineMat3) -> TransformedSurface<S> {
+        TransformedSurface {
+            motion: Some(AnimatedTransform::new(self.transformation.clone(), end_transformation)),
+            ..self
+        }
+    }
+
+    // Calls `f` with the transformation, its inverse and the inverse transposed at the given time
+    fn with_transformations<R>(&self, time: f32, f: impl FnOnce(&AffineMat3, &AffineMat3, &Mat3) -> R) -> R {
+        match &self.motion {
+            None => f(&self.transformation, &self.transformation_inv, &self.transform_inv_t),
+            Some(motion) => {
+                let transformation = motion.interpolate(time);
+                let transformation_inv = transformation.compute_inverse();
 
-        (&self.transform_inv_t * normal).normalize()
+                f(&transformation, &transformation_inv, &transformation_inv.transform_mat.transpose())
+            }
+        }
+    }
+}
+
+
+fn transform_normal(transform_inv_t: &Mat3, normal: &Vec3) -> Vec3 {
+    (transform_inv_t * normal).normalize()
+}
+
+
+fn transform_ray(transformation_inv: &AffineMat3, ray: &Ray) -> Ray {
+    Ray {
+        origin: transformation_inv * &ray.origin,
+        direction: (transformation_inv * &ray.direction).normalize(),
+        time: ray.time,
     }
 }
 
 
 impl<S: Surface> Surface for TransformedSurface<S> {
     fn compute_hit(&self, ray: &Ray, ray_options: RayOptions) -> Option<Hit> {
-        let ray_object = Ray {
-            origin: &self.transformation_inv * &ray.origin,
-            direction: (&self.transformation_inv * &ray.direction).normalize(),
-        };
-
-        if let Some(hit) = self.surface.compute_hit(&ray_object, ray_options) {
-            let hit_point = &self.transformation * &ray_object.compute_point(hit.t);
+        self.with_transformations(ray.time, |transformation, transformation_inv, transform_inv_t| {
+            let ray_object = transform_ray(transformation_inv, ray);
+            let hit = self.surface.compute_hit(&ray_object, ray_options)?;
+            let hit_point = transformation * &ray_object.compute_point(hit.t);
             let t_world = ray.compute_t(&hit_point);
 
-            return Some(Hit::new(t_world, self.transform_normal(&hit.normal)));
-        }
+            Some(Hit {t: t_world, normal: transform_normal(transform_inv_t, &hit.normal), ..hit})
+        })
+    }
+
+    fn is_occluded(&self, ray: &Ray, ray_options: RayOptions, t_max: f32) -> bool {
+        self.with_transformations(ray.time, |_, transformation_inv, _| {
+            // The object space ray has a unit direction, so `t` is scaled by the length of the transformed one
+            let direction_scale = (transformation_inv * &ray.direction).norm();
 
-        None
+            self.surface.is_occluded(&transform_ray(transformation_inv, ray), ray_options, t_max * direction_scale)
+        })
     }
 
     fn get_visual_data(&self) -> VisualData { self.surface.get_visual_data() }
+
+    fn get_hit_visual_data(&self, hit: &Hit) -> VisualData { self.surface.get_hit_visual_data(hit) }
+
+    fn bounding_box(&self) -> Option<AxisAlignedBox> {
+        let bbox = self.surface.bounding_box()?;
+
+        match &self.motion {
+            None => Some(bbox.transform(&self.transformation)),
+            Some(motion) => {
+                // The union of the boxes at the steps contains the straight paths between them, the
+                // padding covers the rotating points which bulge out of these paths
+                let radius = Vec3::new(
+                    bbox.min_corner.x.abs().max(bbox.max_corner.x.abs()),
+                    bbox.min_corner.y.abs().max(bbox.max_corner.y.abs()),
+                    bbox.min_corner.z.abs().max(bbox.max_corner.z.abs()),
+                ).norm();
+                let padding = motion.compute_chord_error(radius, 1.0 / NUM_MOTION_BBOX_STEPS as f32);
+                let union = (0..=NUM_MOTION_BBOX_STEPS)
+                    .map(|step| bbox.transform(&motion.interpolate(step as f32 / NUM_MOTION_BBOX_STEPS as f32)))
+                    .fold(AxisAlignedBox::empty(), |union, step_bbox| union.union(&step_bbox));
+
+                Some(AxisAlignedBox {
+                    min_corner: &union.min_corner + (-padding),
+                    max_corner: &union.max_corner + padding,
+                })
+            },
+        }
+    }
 }
@@ -0,0 +1,152 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::basics::Color;
+use crate::error::RtrsError;
+use crate::tone_mapping::srgb_to_linear;
+
+
+// Defines how texture coordinates outside of [0, 1] are mapped back into the texture
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WrapMode {
+    Repeat,
+    MirroredRepeat,
+    ClampToEdge,
+}
+
+
+impl WrapMode {
+    pub fn from_name(name: &str) -> Option<WrapMode> {
+        match name {
+            "repeat" => Some(WrapMode::Repeat),
+            "mirror" => Some(WrapMode::MirroredRepeat),
+            "clamp" => Some(WrapMode::ClampToEdge),
+            _ => None,
+        }
+    }
+
+    // Maps a texel index into [0, size)
+    fn apply(&self, idx: i64, size: u32) -> u32 {
+        let size = size as i64;
+
+        (match self {
+            WrapMode::Repeat => idx.rem_euclid(size),
+            WrapMode::MirroredRepeat => {
+                let period_idx = idx.rem_euclid(2 * size);
+                if period_idx < size { period_idx } else { 2 * size - 1 - period_idx }
+            },
+            WrapMode::ClampToEdge => idx.max(0).min(size - 1),
+        }) as u32
+    }
+}
+
+
+// An image which is sampled with bilinear filtering. Texels are stored as linear colors
+// with rows going from top to bottom, while v = 0 corresponds to the bottom row (as in OBJ files)
+#[derive(Clone)]
+pub struct Texture {
+    pub width: u32,
+    pub height: u32,
+    pub texels: Vec<Color>,
+    pub wrap_mode: WrapMode,
+}
+
+
+impl Texture {
+    pub fn new(width: u32, height: u32, texels: Vec<Color>, wrap_mode: WrapMode) -> Texture {
+        assert_eq!(texels.len(), (width * height) as usize);
+        assert!(width > 0 && height > 0);
+
+        Texture {
+            width: width,
+            height: height,
+            texels: texels,
+            wrap_mode: wrap_mode,
+        }
+    }
+
+    // Loads any image format supported by the `image` crate (PNG and JPEG in particular).
+    // 8-bit images are assumed to be sRGB encoded
+    pub fn from_file(path: &str, wrap_mode: WrapMode) -> Result<Texture, RtrsError> {
+        let img = image::open(path).map_err(|err| RtrsError::from_image(Path::new(path), err))?.to_rgb8();
+        let texels = img.pixels()
+            .map(|p| Color::new(
+                srgb_to_linear(p[0] as f32 / 255.0),
+                srgb_to_linear(p[1] as f32 / 255.0),
+                srgb_to_linear(p[2] as f32 / 255.0),
+            ))
+            .collect();
+
+        Ok(Texture::new(img.width(), img.height(), texels, wrap_mode))
+    }
+
+    pub fn get_texel(&self, x: i64, y: i64) -> &Color {
+        let x = self.wrap_mode.apply(x, self.width);
+        let y = self.wrap_mode.apply(y, self.height);
+
+        &self.texels[(y * self.width + x) as usize]
+    }
+
+    pub fn sample(&self, u: f32, v: f32) -> Color {
+        // Texel centers are located at half-integer coordinates
+        let x = u * self.width as f32 - 0.5;
+        let y = (1.0 - v) * self.height as f32 - 0.5;
+        let (x_floor, y_floor) = (x.floor(), y.floor());
+        let (dx, dy) = (x - x_floor, y - y_floor);
+        let (x0, y0) = (x_floor as i64, y_floor as i64);
+
+        let top = &(self.get_texel(x0, y0) * (1.0 - dx)) + &(self.get_texel(x0 + 1, y0) * dx);
+        let bottom = &(self.get_texel(x0, y0 + 1) * (1.0 - dx)) + &(self.get_texel(x0 + 1, y0 + 1) * dx);
+
+        &(&top * (1.0 - dy)) + &(&bottom * dy)
+    }
+}
+
+
+// Printing all the texels is of no use
+impl fmt::Debug for Texture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Texture {{ width: {}, height: {}, wrap_mode: {:?} }}", self.width, self.height, self.wrap_mode)
+    }
+}
+
+
+#[cfg(test)]
+mod texture_tests {
+    use super::*;
+
+    fn create_checkerboard(wrap_mode: WrapMode) -> Texture {
+        // Black and white texels, the top row is black-white
+        let texels = vec![Color::zero(), Color::new(1.0, 1.0, 1.0), Color::new(1.0, 1.0, 1.0), Color::zero()];
+
+        Texture::new(2, 2, texels, wrap_mode)
+    }
+
+    #[test]
+    fn test_wrap_modes() {
+        assert_eq!(WrapMode::Repeat.apply(-1, 4), 3);
+        assert_eq!(WrapMode::Repeat.apply(5, 4), 1);
+        assert_eq!(WrapMode::MirroredRepeat.apply(-1, 4), 0);
+        assert_eq!(WrapMode::MirroredRepeat.apply(5, 4), 2);
+        assert_eq!(WrapMode::ClampToEdge.apply(-3, 4), 0);
+        assert_eq!(WrapMode::ClampToEdge.apply(7, 4), 3);
+    }
+
+    #[test]
+    fn test_bilinear_sampling() {
+        let texture = create_checkerboard(WrapMode::ClampToEdge);
+
+        // Texel centers give the exact values
+        assert!(approx_eq!(f32, texture.sample(0.25, 0.75).r, 0.0));
+        assert!(approx_eq!(f32, texture.sample(0.75, 0.75).r, 1.0));
+        // The center is the average of all the four texels
+        assert!(approx_eq!(f32, texture.sample(0.5, 0.5).r, 0.5));
+        // Between the top texels
+        assert!(approx_eq!(f32, texture.sample(0.5, 0.75).g, 0.5));
+
+        // Repeating wraps the right column around to the left one
+        let repeated = create_checkerboard(WrapMode::Repeat);
+        assert!(approx_eq!(f32, repeated.sample(0.0, 0.75).b, 0.5));
+        assert!(approx_eq!(f32, repeated.sample(1.25, 0.75).b, 0.0));
+    }
+}
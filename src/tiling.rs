@@ -0,0 +1,150 @@
+// Splitting of the frame into square tiles for the rasterizer. Triangles are binned into the
+// tiles they overlap, so the tiles can be rasterized in parallel, each with its own depth buffer
+pub const TILE_SIZE: usize = 64;
+
+
+// A rectangle of pixels, the max coordinates are exclusive
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tile {
+    pub x_min: usize,
+    pub y_min: usize,
+    pub x_max: usize,
+    pub y_max: usize,
+}
+
+
+// Bounding box of a triangle in screen space
+#[derive(Debug, Clone)]
+pub struct ScreenBounds {
+    pub x_min: f32,
+    pub y_min: f32,
+    pub x_max: f32,
+    pub y_max: f32,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct TileGrid {
+    pub width: usize,
+    pub height: usize,
+    pub tile_size: usize,
+    pub num_tiles_x: usize,
+    pub num_tiles_y: usize,
+}
+
+
+impl Tile {
+    pub fn width(&self) -> usize {
+        self.x_max - self.x_min
+    }
+
+    pub fn height(&self) -> usize {
+        self.y_max - self.y_min
+    }
+}
+
+
+impl TileGrid {
+    pub fn new(width: usize, height: usize, tile_size: usize) -> TileGrid {
+        assert!(tile_size > 0);
+
+        TileGrid {
+            width: width,
+            height: height,
+            tile_size: tile_size,
+            num_tiles_x: width.div_ceil(tile_size),
+            num_tiles_y: height.div_ceil(tile_size),
+        }
+    }
+
+    pub fn num_tiles(&self) -> usize {
+        self.num_tiles_x * self.num_tiles_y
+    }
+
+    // Tiles are numbered row by row, the last ones in a row or a column can be smaller
+    pub fn get_tile(&self, idx: usize) -> Tile {
+        let x_min = (idx % self.num_tiles_x) * self.tile_size;
+        let y_min = (idx / self.num_tiles_x) * self.tile_size;
+
+        Tile {
+            x_min: x_min,
+            y_min: y_min,
+            x_max: (x_min + self.tile_size).min(self.width),
+            y_max: (y_min + self.tile_size).min(self.height),
+        }
+    }
+
+    // Returns the range of tile columns or rows which the [min, max] pixel range overlaps
+    fn compute_tile_range(&self, min: f32, max: f32, num_tiles: usize) -> Option<(usize, usize)> {
+        if max < 0.0 || min >= (num_tiles * self.tile_size) as f32 || min > max {
+            return None;
+        }
+
+        let first = (min.max(0.0) as usize) / self.tile_size;
+        let last = ((max as usize) / self.tile_size).min(num_tiles - 1);
+
+        Some((first, last))
+    }
+
+    // For each tile, gives the indices of the triangles which overlap it. The indices are kept
+    // in the original order, so the depth test resolves equal depths in the same way as when
+    // rasterizing the whole frame sequentially
+    pub fn bin(&self, bounds: &[ScreenBounds]) -> Vec<Vec<usize>> {
+        let mut bins = vec![Vec::new(); self.num_tiles()];
+
+        for (i, b) in bounds.iter().enumerate() {
+            let x_range = self.compute_tile_range(b.x_min, b.x_max, self.num_tiles_x);
+            let y_range = self.compute_tile_range(b.y_min, b.y_max, self.num_tiles_y);
+
+            if let (Some((x_first, x_last)), Some((y_first, y_last))) = (x_range, y_range) {
+                for tile_y in y_first..(y_last + 1) {
+                    for tile_x in x_first..(x_last + 1) {
+                        bins[tile_y * self.num_tiles_x + tile_x].push(i);
+                    }
+                }
+            }
+        }
+
+        bins
+    }
+}
+
+
+#[cfg(test)]
+mod tiling_tests {
+    use super::*;
+
+    #[test]
+    fn test_tiles() {
+        let grid = TileGrid::new(100, 70, 32);
+
+        assert_eq!(grid.num_tiles_x, 4);
+        assert_eq!(grid.num_tiles_y, 3);
+        assert_eq!(grid.get_tile(1), Tile {x_min: 32, y_min: 0, x_max: 64, y_max: 32});
+        // The last tile is cut by the frame borders
+        assert_eq!(grid.get_tile(11), Tile {x_min: 96, y_min: 64, x_max: 100, y_max: 70});
+
+        let num_pixels: usize = (0..grid.num_tiles()).map(|i| grid.get_tile(i)).map(|t| t.width() * t.height()).sum();
+        assert_eq!(num_pixels, 100 * 70);
+    }
+
+    #[test]
+    fn test_binning() {
+        let grid = TileGrid::new(100, 70, 32);
+        let bounds = [
+            ScreenBounds {x_min: 1.0, y_min: 1.0, x_max: 10.0, y_max: 10.0},   // Inside the first tile
+            ScreenBounds {x_min: 30.0, y_min: 60.0, x_max: 40.0, y_max: 70.0}, // Four tiles at the bottom
+            ScreenBounds {x_min: -20.0, y_min: 5.0, x_max: -1.0, y_max: 6.0},  // To the left of the frame
+            ScreenBounds {x_min: -5.0, y_min: 5.0, x_max: 200.0, y_max: 6.0},  // The whole first row
+        ];
+        let bins = grid.bin(&bounds);
+
+        assert_eq!(bins[0], vec![0, 3]);
+        assert_eq!(bins[3], vec![3]);
+        for tile_idx in [4, 5, 8, 9].iter() {
+            assert_eq!(bins[*tile_idx], vec![1]);
+        }
+        assert!(bins[6].is_empty());
+        assert!(bins.iter().all(|bin| !bin.contains(&2)));
+    }
+}
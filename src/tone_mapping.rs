@@ -0,0 +1,127 @@
+use crate::basics::Color;
+
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ToneMappingOperator {
+    Clamp,
+    Reinhard,
+    AcesFilmic,
+}
+
+
+#[derive(Debug, Copy, Clone)]
+pub struct ToneMapping {
+    pub operator: ToneMappingOperator,
+    // In stops, i.e. radiance is scaled by 2^exposure before the operator is applied
+    pub exposure: f32,
+}
+
+
+impl ToneMappingOperator {
+    pub fn next(&self) -> ToneMappingOperator {
+        match self {
+            ToneMappingOperator::Clamp => ToneMappingOperator::Reinhard,
+            ToneMappingOperator::Reinhard => ToneMappingOperator::AcesFilmic,
+            ToneMappingOperator::AcesFilmic => ToneMappingOperator::Clamp,
+        }
+    }
+}
+
+
+impl ToneMapping {
+    pub fn new(operator: ToneMappingOperator) -> ToneMapping {
+        ToneMapping {
+            operator: operator,
+            exposure: 0.0,
+        }
+    }
+
+    // Maps linear radiance into [0, 1], the result is still linear (no gamma encoding)
+    pub fn apply(&self, color: &Color) -> Color {
+        let color = color * 2.0_f32.powf(self.exposure);
+
+        match self.operator {
+            ToneMappingOperator::Clamp => color.clamp(),
+            ToneMappingOperator::Reinhard => {
+                // Luminance based, so the hue of bright colors is preserved
+                let luminance = color.luminance();
+                if luminance <= 0.0 {
+                    return Color::zero();
+                }
+
+                (&color * (1.0 / (1.0 + luminance))).clamp()
+            },
+            ToneMappingOperator::AcesFilmic => Color::new(
+                aces_filmic(color.r),
+                aces_filmic(color.g),
+                aces_filmic(color.b),
+            ),
+        }
+    }
+}
+
+
+// Curve fit of the ACES reference rendering transform by Krzysztof Narkowicz
+fn aces_filmic(x: f32) -> f32 {
+    let x = x.max(0.0);
+    let (a, b, c, d, e) = (2.51, 0.03, 2.43, 0.59, 0.14);
+
+    ((x * (a * x + b)) / (x * (c * x + d) + e)).clamp(0.0, 1.0)
+}
+
+
+pub fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+
+pub fn linear_to_srgb(x: f32) -> f32 {
+    if x <= 0.0031308 {
+        12.92 * x
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+
+#[cfg(test)]
+mod tone_mapping_tests {
+    use super::*;
+
+    #[test]
+    fn test_operators_map_into_unit_range() {
+        let bright = Color::new(8.0, 2.0, 0.5);
+
+        for operator in &[ToneMappingOperator::Clamp, ToneMappingOperator::Reinhard, ToneMappingOperator::AcesFilmic] {
+            let mapped = ToneMapping::new(*operator).apply(&bright);
+            assert!(mapped.r <= 1.0 && mapped.g <= 1.0 && mapped.b <= 1.0);
+            assert!(mapped.r >= 0.0 && mapped.g >= 0.0 && mapped.b >= 0.0);
+        }
+
+        let reinhard = ToneMapping::new(ToneMappingOperator::Reinhard).apply(&bright);
+        assert!(reinhard.r > reinhard.g && reinhard.g > reinhard.b);
+    }
+
+    #[test]
+    fn test_exposure() {
+        let mut tone_mapping = ToneMapping::new(ToneMappingOperator::Clamp);
+        tone_mapping.exposure = 1.0;
+
+        let mapped = tone_mapping.apply(&Color::new(0.25, 0.5, 1.0));
+        assert!(approx_eq!(f32, mapped.r, 0.5, ulps = 2));
+        assert!(approx_eq!(f32, mapped.g, 1.0, ulps = 2));
+        assert!(approx_eq!(f32, mapped.b, 1.0, ulps = 2));
+    }
+
+    #[test]
+    fn test_linear_to_srgb() {
+        assert!(approx_eq!(f32, linear_to_srgb(0.0), 0.0, ulps = 2));
+        assert!(approx_eq!(f32, linear_to_srgb(1.0), 1.0, epsilon = 1e-6));
+        assert!((linear_to_srgb(0.214) - 0.5).abs() < 1e-2);
+        assert!((srgb_to_linear(linear_to_srgb(0.42)) - 0.42).abs() < 1e-6);
+    }
+}
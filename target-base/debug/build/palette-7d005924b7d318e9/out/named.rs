@@ -0,0 +1,629 @@
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: aliceblue;"></div>
+pub const ALICEBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 240, green: 248, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: antiquewhite;"></div>
+pub const ANTIQUEWHITE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 250, green: 235, blue: 215, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: aqua;"></div>
+pub const AQUA: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 255, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: aquamarine;"></div>
+pub const AQUAMARINE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 127, green: 255, blue: 212, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: azure;"></div>
+pub const AZURE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 240, green: 255, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: beige;"></div>
+pub const BEIGE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 245, green: 245, blue: 220, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: bisque;"></div>
+pub const BISQUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 228, blue: 196, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: black;"></div>
+pub const BLACK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 0, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: blanchedalmond;"></div>
+pub const BLANCHEDALMOND: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 235, blue: 205, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: blue;"></div>
+pub const BLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 0, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: blueviolet;"></div>
+pub const BLUEVIOLET: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 138, green: 43, blue: 226, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: brown;"></div>
+pub const BROWN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 165, green: 42, blue: 42, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: burlywood;"></div>
+pub const BURLYWOOD: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 222, green: 184, blue: 135, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: cadetblue;"></div>
+pub const CADETBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 95, green: 158, blue: 160, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: chartreuse;"></div>
+pub const CHARTREUSE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 127, green: 255, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: chocolate;"></div>
+pub const CHOCOLATE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 210, green: 105, blue: 30, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: coral;"></div>
+pub const CORAL: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 127, blue: 80, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: cornflowerblue;"></div>
+pub const CORNFLOWERBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 100, green: 149, blue: 237, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: cornsilk;"></div>
+pub const CORNSILK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 248, blue: 220, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: crimson;"></div>
+pub const CRIMSON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 220, green: 20, blue: 60, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: cyan;"></div>
+pub const CYAN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 255, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkblue;"></div>
+pub const DARKBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 0, blue: 139, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkcyan;"></div>
+pub const DARKCYAN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 139, blue: 139, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkgoldenrod;"></div>
+pub const DARKGOLDENROD: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 184, green: 134, blue: 11, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkgray;"></div>
+pub const DARKGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 169, green: 169, blue: 169, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkgreen;"></div>
+pub const DARKGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 100, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkgrey;"></div>
+pub const DARKGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 169, green: 169, blue: 169, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkkhaki;"></div>
+pub const DARKKHAKI: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 189, green: 183, blue: 107, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkmagenta;"></div>
+pub const DARKMAGENTA: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 139, green: 0, blue: 139, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkolivegreen;"></div>
+pub const DARKOLIVEGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 85, green: 107, blue: 47, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkorange;"></div>
+pub const DARKORANGE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 140, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkorchid;"></div>
+pub const DARKORCHID: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 153, green: 50, blue: 204, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkred;"></div>
+pub const DARKRED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 139, green: 0, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darksalmon;"></div>
+pub const DARKSALMON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 233, green: 150, blue: 122, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkseagreen;"></div>
+pub const DARKSEAGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 143, green: 188, blue: 143, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkslateblue;"></div>
+pub const DARKSLATEBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 72, green: 61, blue: 139, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkslategray;"></div>
+pub const DARKSLATEGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 47, green: 79, blue: 79, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkslategrey;"></div>
+pub const DARKSLATEGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 47, green: 79, blue: 79, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkturquoise;"></div>
+pub const DARKTURQUOISE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 206, blue: 209, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: darkviolet;"></div>
+pub const DARKVIOLET: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 148, green: 0, blue: 211, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: deeppink;"></div>
+pub const DEEPPINK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 20, blue: 147, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: deepskyblue;"></div>
+pub const DEEPSKYBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 191, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: dimgray;"></div>
+pub const DIMGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 105, green: 105, blue: 105, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: dimgrey;"></div>
+pub const DIMGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 105, green: 105, blue: 105, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: dodgerblue;"></div>
+pub const DODGERBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 30, green: 144, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: firebrick;"></div>
+pub const FIREBRICK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 178, green: 34, blue: 34, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: floralwhite;"></div>
+pub const FLORALWHITE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 250, blue: 240, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: forestgreen;"></div>
+pub const FORESTGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 34, green: 139, blue: 34, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: fuchsia;"></div>
+pub const FUCHSIA: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 0, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: gainsboro;"></div>
+pub const GAINSBORO: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 220, green: 220, blue: 220, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: ghostwhite;"></div>
+pub const GHOSTWHITE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 248, green: 248, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: gold;"></div>
+pub const GOLD: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 215, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: goldenrod;"></div>
+pub const GOLDENROD: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 218, green: 165, blue: 32, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: gray;"></div>
+pub const GRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 128, green: 128, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: grey;"></div>
+pub const GREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 128, green: 128, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: green;"></div>
+pub const GREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 128, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: greenyellow;"></div>
+pub const GREENYELLOW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 173, green: 255, blue: 47, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: honeydew;"></div>
+pub const HONEYDEW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 240, green: 255, blue: 240, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: hotpink;"></div>
+pub const HOTPINK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 105, blue: 180, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: indianred;"></div>
+pub const INDIANRED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 205, green: 92, blue: 92, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: indigo;"></div>
+pub const INDIGO: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 75, green: 0, blue: 130, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: ivory;"></div>
+pub const IVORY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 255, blue: 240, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: khaki;"></div>
+pub const KHAKI: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 240, green: 230, blue: 140, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lavender;"></div>
+pub const LAVENDER: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 230, green: 230, blue: 250, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lavenderblush;"></div>
+pub const LAVENDERBLUSH: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 240, blue: 245, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lawngreen;"></div>
+pub const LAWNGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 124, green: 252, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lemonchiffon;"></div>
+pub const LEMONCHIFFON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 250, blue: 205, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightblue;"></div>
+pub const LIGHTBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 173, green: 216, blue: 230, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightcoral;"></div>
+pub const LIGHTCORAL: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 240, green: 128, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightcyan;"></div>
+pub const LIGHTCYAN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 224, green: 255, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightgoldenrodyellow;"></div>
+pub const LIGHTGOLDENRODYELLOW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 250, green: 250, blue: 210, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightgray;"></div>
+pub const LIGHTGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 211, green: 211, blue: 211, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightgreen;"></div>
+pub const LIGHTGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 144, green: 238, blue: 144, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightgrey;"></div>
+pub const LIGHTGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 211, green: 211, blue: 211, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightpink;"></div>
+pub const LIGHTPINK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 182, blue: 193, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightsalmon;"></div>
+pub const LIGHTSALMON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 160, blue: 122, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightseagreen;"></div>
+pub const LIGHTSEAGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 32, green: 178, blue: 170, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightskyblue;"></div>
+pub const LIGHTSKYBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 135, green: 206, blue: 250, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightslategray;"></div>
+pub const LIGHTSLATEGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 119, green: 136, blue: 153, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightslategrey;"></div>
+pub const LIGHTSLATEGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 119, green: 136, blue: 153, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightsteelblue;"></div>
+pub const LIGHTSTEELBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 176, green: 196, blue: 222, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lightyellow;"></div>
+pub const LIGHTYELLOW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 255, blue: 224, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: lime;"></div>
+pub const LIME: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 255, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: limegreen;"></div>
+pub const LIMEGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 50, green: 205, blue: 50, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: linen;"></div>
+pub const LINEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 250, green: 240, blue: 230, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: magenta;"></div>
+pub const MAGENTA: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 0, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: maroon;"></div>
+pub const MAROON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 128, green: 0, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumaquamarine;"></div>
+pub const MEDIUMAQUAMARINE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 102, green: 205, blue: 170, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumblue;"></div>
+pub const MEDIUMBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 0, blue: 205, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumorchid;"></div>
+pub const MEDIUMORCHID: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 186, green: 85, blue: 211, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumpurple;"></div>
+pub const MEDIUMPURPLE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 147, green: 112, blue: 219, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumseagreen;"></div>
+pub const MEDIUMSEAGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 60, green: 179, blue: 113, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumslateblue;"></div>
+pub const MEDIUMSLATEBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 123, green: 104, blue: 238, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumspringgreen;"></div>
+pub const MEDIUMSPRINGGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 250, blue: 154, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumturquoise;"></div>
+pub const MEDIUMTURQUOISE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 72, green: 209, blue: 204, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mediumvioletred;"></div>
+pub const MEDIUMVIOLETRED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 199, green: 21, blue: 133, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: midnightblue;"></div>
+pub const MIDNIGHTBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 25, green: 25, blue: 112, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mintcream;"></div>
+pub const MINTCREAM: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 245, green: 255, blue: 250, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: mistyrose;"></div>
+pub const MISTYROSE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 228, blue: 225, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: moccasin;"></div>
+pub const MOCCASIN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 228, blue: 181, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: navajowhite;"></div>
+pub const NAVAJOWHITE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 222, blue: 173, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: navy;"></div>
+pub const NAVY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 0, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: oldlace;"></div>
+pub const OLDLACE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 253, green: 245, blue: 230, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: olive;"></div>
+pub const OLIVE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 128, green: 128, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: olivedrab;"></div>
+pub const OLIVEDRAB: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 107, green: 142, blue: 35, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: orange;"></div>
+pub const ORANGE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 165, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: orangered;"></div>
+pub const ORANGERED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 69, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: orchid;"></div>
+pub const ORCHID: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 218, green: 112, blue: 214, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: palegoldenrod;"></div>
+pub const PALEGOLDENROD: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 238, green: 232, blue: 170, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: palegreen;"></div>
+pub const PALEGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 152, green: 251, blue: 152, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: paleturquoise;"></div>
+pub const PALETURQUOISE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 175, green: 238, blue: 238, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: palevioletred;"></div>
+pub const PALEVIOLETRED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 219, green: 112, blue: 147, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: papayawhip;"></div>
+pub const PAPAYAWHIP: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 239, blue: 213, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: peachpuff;"></div>
+pub const PEACHPUFF: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 218, blue: 185, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: peru;"></div>
+pub const PERU: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 205, green: 133, blue: 63, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: pink;"></div>
+pub const PINK: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 192, blue: 203, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: plum;"></div>
+pub const PLUM: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 221, green: 160, blue: 221, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: powderblue;"></div>
+pub const POWDERBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 176, green: 224, blue: 230, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: purple;"></div>
+pub const PURPLE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 128, green: 0, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: rebeccapurple;"></div>
+pub const REBECCAPURPLE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 102, green: 51, blue: 153, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: red;"></div>
+pub const RED: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 0, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: rosybrown;"></div>
+pub const ROSYBROWN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 188, green: 143, blue: 143, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: royalblue;"></div>
+pub const ROYALBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 65, green: 105, blue: 225, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: saddlebrown;"></div>
+pub const SADDLEBROWN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 139, green: 69, blue: 19, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: salmon;"></div>
+pub const SALMON: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 250, green: 128, blue: 114, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: sandybrown;"></div>
+pub const SANDYBROWN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 244, green: 164, blue: 96, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: seagreen;"></div>
+pub const SEAGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 46, green: 139, blue: 87, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: seashell;"></div>
+pub const SEASHELL: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 245, blue: 238, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: sienna;"></div>
+pub const SIENNA: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 160, green: 82, blue: 45, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: silver;"></div>
+pub const SILVER: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 192, green: 192, blue: 192, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: skyblue;"></div>
+pub const SKYBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 135, green: 206, blue: 235, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: slateblue;"></div>
+pub const SLATEBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 106, green: 90, blue: 205, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: slategray;"></div>
+pub const SLATEGRAY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 112, green: 128, blue: 144, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: slategrey;"></div>
+pub const SLATEGREY: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 112, green: 128, blue: 144, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: snow;"></div>
+pub const SNOW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 250, blue: 250, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: springgreen;"></div>
+pub const SPRINGGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 255, blue: 127, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: steelblue;"></div>
+pub const STEELBLUE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 70, green: 130, blue: 180, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: tan;"></div>
+pub const TAN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 210, green: 180, blue: 140, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: teal;"></div>
+pub const TEAL: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 0, green: 128, blue: 128, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: thistle;"></div>
+pub const THISTLE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 216, green: 191, blue: 216, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: tomato;"></div>
+pub const TOMATO: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 99, blue: 71, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: turquoise;"></div>
+pub const TURQUOISE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 64, green: 224, blue: 208, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: violet;"></div>
+pub const VIOLET: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 238, green: 130, blue: 238, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: wheat;"></div>
+pub const WHEAT: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 245, green: 222, blue: 179, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: white;"></div>
+pub const WHITE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 255, blue: 255, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: whitesmoke;"></div>
+pub const WHITESMOKE: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 245, green: 245, blue: 245, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: yellow;"></div>
+pub const YELLOW: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 255, green: 255, blue: 0, standard: ::core::marker::PhantomData };
+
+///<div style="display: inline-block; width: 3em; height: 1em; border: 1px solid black; background: yellowgreen;"></div>
+pub const YELLOWGREEN: ::rgb::Srgb<u8> = ::rgb::Srgb { red: 154, green: 205, blue: 50, standard: ::core::marker::PhantomData };
+static COLORS: ::phf::Map<&'static str, ::rgb::Srgb<u8>> = ::phf::Map {
+    key: 3213172566270843353,
+    disps: ::phf::Slice::Static(&[
+        (0, 0),
+        (15, 22),
+        (0, 18),
+        (1, 3),
+        (0, 29),
+        (5, 128),
+        (0, 0),
+        (0, 39),
+        (0, 131),
+        (0, 92),
+        (0, 2),
+        (0, 82),
+        (2, 45),
+        (6, 104),
+        (1, 2),
+        (29, 54),
+        (1, 0),
+        (3, 139),
+        (0, 9),
+        (78, 32),
+        (0, 1),
+        (12, 98),
+        (0, 0),
+        (0, 2),
+        (69, 6),
+        (0, 2),
+        (0, 78),
+        (1, 0),
+        (0, 135),
+        (6, 71),
+    ]),
+    entries: ::phf::Slice::Static(&[
+        ("palevioletred", PALEVIOLETRED),
+        ("lightyellow", LIGHTYELLOW),
+        ("paleturquoise", PALETURQUOISE),
+        ("pink", PINK),
+        ("khaki", KHAKI),
+        ("darkgrey", DARKGREY),
+        ("violet", VIOLET),
+        ("slateblue", SLATEBLUE),
+        ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+        ("deepskyblue", DEEPSKYBLUE),
+        ("gold", GOLD),
+        ("lightcyan", LIGHTCYAN),
+        ("papayawhip", PAPAYAWHIP),
+        ("lightgray", LIGHTGRAY),
+        ("mediumturquoise", MEDIUMTURQUOISE),
+        ("red", RED),
+        ("plum", PLUM),
+        ("palegoldenrod", PALEGOLDENROD),
+        ("lightskyblue", LIGHTSKYBLUE),
+        ("tomato", TOMATO),
+        ("aquamarine", AQUAMARINE),
+        ("peachpuff", PEACHPUFF),
+        ("floralwhite", FLORALWHITE),
+        ("ivory", IVORY),
+        ("deeppink", DEEPPINK),
+        ("oldlace", OLDLACE),
+        ("firebrick", FIREBRICK),
+        ("springgreen", SPRINGGREEN),
+        ("green", GREEN),
+        ("fuchsia", FUCHSIA),
+        ("yellowgreen", YELLOWGREEN),
+        ("mistyrose", MISTYROSE),
+        ("lightseagreen", LIGHTSEAGREEN),
+        ("darkorchid", DARKORCHID),
+        ("white", WHITE),
+        ("linen", LINEN),
+        ("grey", GREY),
+        ("cornsilk", CORNSILK),
+        ("beige", BEIGE),
+        ("mediumpurple", MEDIUMPURPLE),
+        ("lightslategrey", LIGHTSLATEGREY),
+        ("antiquewhite", ANTIQUEWHITE),
+        ("coral", CORAL),
+        ("thistle", THISTLE),
+        ("gray", GRAY),
+        ("orange", ORANGE),
+        ("darkgoldenrod", DARKGOLDENROD),
+        ("turquoise", TURQUOISE),
+        ("indianred", INDIANRED),
+        ("darkslateblue", DARKSLATEBLUE),
+        ("darkturquoise", DARKTURQUOISE),
+        ("cyan", CYAN),
+        ("mediumorchid", MEDIUMORCHID),
+        ("mediumseagreen", MEDIUMSEAGREEN),
+        ("maroon", MAROON),
+        ("darkkhaki", DARKKHAKI),
+        ("palegreen", PALEGREEN),
+        ("darkmagenta", DARKMAGENTA),
+        ("blue", BLUE),
+        ("cadetblue", CADETBLUE),
+        ("bisque", BISQUE),
+        ("whitesmoke", WHITESMOKE),
+        ("yellow", YELLOW),
+        ("darkorange", DARKORANGE),
+        ("dimgrey", DIMGREY),
+        ("darkslategray", DARKSLATEGRAY),
+        ("ghostwhite", GHOSTWHITE),
+        ("purple", PURPLE),
+        ("chocolate", CHOCOLATE),
+        ("rebeccapurple", REBECCAPURPLE),
+        ("magenta", MAGENTA),
+        ("darkgreen", DARKGREEN),
+        ("orchid", ORCHID),
+        ("darkslategrey", DARKSLATEGREY),
+        ("blanchedalmond", BLANCHEDALMOND),
+        ("lavender", LAVENDER),
+        ("mintcream", MINTCREAM),
+        ("snow", SNOW),
+        ("mediumslateblue", MEDIUMSLATEBLUE),
+        ("lavenderblush", LAVENDERBLUSH),
+        ("powderblue", POWDERBLUE),
+        ("seashell", SEASHELL),
+        ("greenyellow", GREENYELLOW),
+        ("sandybrown", SANDYBROWN),
+        ("darkseagreen", DARKSEAGREEN),
+        ("darkblue", DARKBLUE),
+        ("mediumspringgreen", MEDIUMSPRINGGREEN),
+        ("darkred", DARKRED),
+        ("peru", PERU),
+        ("cornflowerblue", CORNFLOWERBLUE),
+        ("mediumblue", MEDIUMBLUE),
+        ("lime", LIME),
+        ("skyblue", SKYBLUE),
+        ("orangered", ORANGERED),
+        ("darkgray", DARKGRAY),
+        ("mediumaquamarine", MEDIUMAQUAMARINE),
+        ("lightpink", LIGHTPINK),
+        ("honeydew", HONEYDEW),
+        ("aqua", AQUA),
+        ("indigo", INDIGO),
+        ("aliceblue", ALICEBLUE),
+        ("royalblue", ROYALBLUE),
+        ("navy", NAVY),
+        ("lightslategray", LIGHTSLATEGRAY),
+        ("teal", TEAL),
+        ("lightcoral", LIGHTCORAL),
+        ("burlywood", BURLYWOOD),
+        ("tan", TAN),
+        ("lightsalmon", LIGHTSALMON),
+        ("goldenrod", GOLDENROD),
+        ("saddlebrown", SADDLEBROWN),
+        ("lightgrey", LIGHTGREY),
+        ("lightgreen", LIGHTGREEN),
+        ("black", BLACK),
+        ("slategray", SLATEGRAY),
+        ("darkviolet", DARKVIOLET),
+        ("darkolivegreen", DARKOLIVEGREEN),
+        ("limegreen", LIMEGREEN),
+        ("silver", SILVER),
+        ("midnightblue", MIDNIGHTBLUE),
+        ("slategrey", SLATEGREY),
+        ("sienna", SIENNA),
+        ("crimson", CRIMSON),
+        ("darkcyan", DARKCYAN),
+        ("dimgray", DIMGRAY),
+        ("forestgreen", FORESTGREEN),
+        ("olivedrab", OLIVEDRAB),
+        ("chartreuse", CHARTREUSE),
+        ("darksalmon", DARKSALMON),
+        ("salmon", SALMON),
+        ("brown", BROWN),
+        ("seagreen", SEAGREEN),
+        ("steelblue", STEELBLUE),
+        ("lightsteelblue", LIGHTSTEELBLUE),
+        ("lemonchiffon", LEMONCHIFFON),
+        ("mediumvioletred", MEDIUMVIOLETRED),
+        ("rosybrown", ROSYBROWN),
+        ("lightblue", LIGHTBLUE),
+        ("gainsboro", GAINSBORO),
+        ("wheat", WHEAT),
+        ("lawngreen", LAWNGREEN),
+        ("moccasin", MOCCASIN),
+        ("hotpink", HOTPINK),
+        ("navajowhite", NAVAJOWHITE),
+        ("azure", AZURE),
+        ("dodgerblue", DODGERBLUE),
+        ("olive", OLIVE),
+        ("blueviolet", BLUEVIOLET),
+    ]),
+};
\ No newline at end of file